@@ -4,6 +4,7 @@ mod handler;
 mod message;
 mod context;
 mod state;
+mod supervisor;
 
 pub use self::{
     context::*,
@@ -11,8 +12,11 @@ pub use self::{
     handler::*,
     message::*,
     state::*,
+    supervisor::*,
 };
 
+use std::time::Duration;
+
 use crate::errors::ActorError;
 use crate::identifier::IntoActorId;
 
@@ -25,6 +29,28 @@ pub trait Actor: 'static + Sync + Send + Sized {
         tracing::debug!(name: "actor", "activate");
         Ok(())
     }
+    
+    /// Called once the Actor has stopped processing messages, before it is removed from the registry.
+    /// By then its mailbox is closed and its children have stopped.
+    /// 
+    /// This is the place to flush buffers, release resources or write a final snapshot.
+    /// A failed instance replaced by its supervisor is deactivated as well, with [`StopReason::Restarted`].
+    #[allow(unused_variables)]
+    async fn deactivate(&mut self, reason: StopReason, ctx: &mut Self::Context) -> Result<(), ActorError> {
+        tracing::debug!(name: "actor", "deactivate: {:?}", reason);
+        Ok(())
+    }
+    
+    /// Strategy applied when a message fails, unless one is given at spawn time.
+    fn supervisor() -> SupervisorStrategy<Self> {
+        SupervisorStrategy::default()
+    }
+    
+    /// Stop the Actor with [`StopReason::Passivated`] once it has received no message for this long, 
+    /// unless a timeout is given at spawn time.
+    fn idle_timeout() -> Option<Duration> {
+        None
+    }
 }
 
 impl<A: Actor> IntoActor for A {
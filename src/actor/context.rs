@@ -1,6 +1,10 @@
-use crate::actor::{RunningState, State};
+use std::time::Duration;
+
+use crate::actor::{Actor, Handler, Message, RunningState, State, StopReason, Terminated};
+use crate::actor::refs::{ActorRef, AnyRef, Detached, Trace};
+use crate::errors::ActorError;
 use crate::identifier::{ActorId, IntoActorId};
-use crate::system::ActorSystem;
+use crate::system::{ActorSystem, LutetiumActorSystem, SpawnConfig, TimerHandle};
 
 
 /// A structure representing the current state of the managed Actor.
@@ -10,7 +14,7 @@ use crate::system::ActorSystem;
 pub struct Context {
     id: ActorId,
     system: ActorSystem,
-    state: RunningState
+    state: RunningState,
 }
 
 #[async_trait::async_trait]
@@ -27,7 +31,7 @@ impl ActorContext for Context {
     async fn shutdown(&self) {
         self.state.switch(|prev| async move { 
             let mut write = prev.write().await;
-            *write = State::Shutdown;
+            *write = State::Shutdown(StopReason::Terminated);
         }).await;
     }
     
@@ -48,6 +52,83 @@ pub trait ActorContext: 'static + Sync + Send + Sized {
     async fn shutdown(&self);
     fn state(&self) -> &RunningState;
     fn system(&self) -> &ActorSystem;
+    
+    /// Typed reference to the Actor owning this context, available from [`Actor::activate`] onwards.
+    /// 
+    /// Fails with [`ActorError::TypeMismatch`] if `A` is not the Actor owning this context.
+    fn myself<A: Actor>(&self) -> Result<ActorRef<A>, ActorError> {
+        self.state()
+            .myself()
+            .ok_or_else(|| ActorError::NotFoundActor { id: self.id().clone() })?
+            .downcast::<A>()
+    }
+    
+    /// Spawn an Actor owned by the Actor of this context.
+    /// 
+    /// The child is stopped, and waited for, before its parent stops, 
+    /// and a failure it escalates through [`Directive::Escalate`](crate::actor::Directive::Escalate) 
+    /// is decided by the supervisor of its parent.
+    async fn spawn_child<C: Actor>(&self, id: impl IntoActorId, actor: C) -> Result<ActorRef<C>, ActorError> {
+        self.spawn_child_with(id, actor, SpawnConfig::default()).await
+    }
+    
+    async fn spawn_child_with<C: Actor>(&self, id: impl IntoActorId, actor: C, config: SpawnConfig<C>) -> Result<ActorRef<C>, ActorError> {
+        let parent = self.state()
+            .myself()
+            .ok_or_else(|| ActorError::NotFoundActor { id: self.id().clone() })?;
+        self.system().spawn_with(id, actor, config.child_of(parent)).await
+    }
+    
+    /// Children spawned through [`ActorContext::spawn_child`] that are still running.
+    fn children(&self) -> Vec<AnyRef> {
+        self.state()
+            .myself()
+            .map(|myself| myself.cell().children())
+            .unwrap_or_default()
+    }
+    
+    /// Receive [`Terminated`] through `Handler<Terminated>` of `A`, the Actor owning this context, 
+    /// once `target` has stopped.
+    /// 
+    /// Watching an Actor that has already stopped delivers [`Terminated`] right away.
+    /// Like the other messages of the system, it is put into the mailbox even when the mailbox is full.
+    /// 
+    /// ```ignore
+    /// ctx.watch::<Self, _>(&other)?;
+    /// ```
+    fn watch<A, B: Actor>(&self, target: &ActorRef<B>) -> Result<(), ActorError>
+        where A: Actor + Handler<Terminated>
+    {
+        let watcher = self.myself::<A>()?;
+        let cell = watcher.cell.clone();
+        target.cell.watched_by(&cell, Box::new(move |terminated| {
+            let notify = Detached { message: terminated, trace: Trace::capture() };
+            if let Err(e) = watcher.enqueue_control(Box::new(notify)) {
+                tracing::warn!("watcher actor: {} could not be notified: {}", watcher.id(), e);
+            }
+        }));
+        Ok(())
+    }
+    
+    fn unwatch<B: Actor>(&self, target: &ActorRef<B>) {
+        if let Some(myself) = self.state().myself() {
+            target.cell.unwatched_by(myself.cell());
+        }
+    }
+    
+    /// See [`ActorSystem::schedule_once`].
+    fn schedule_once<A, M: Message>(&self, target: &ActorRef<A>, delay: Duration, msg: M) -> TimerHandle
+        where A: Actor + Handler<M>
+    {
+        self.system().schedule_once(target, delay, msg)
+    }
+    
+    /// See [`ActorSystem::schedule_interval`].
+    fn schedule_interval<A, M: Message + Clone>(&self, target: &ActorRef<A>, interval: Duration, msg: M) -> TimerHandle
+        where A: Actor + Handler<M>
+    {
+        self.system().schedule_interval(target, interval, msg)
+    }
 }
 
 #[async_trait::async_trait]
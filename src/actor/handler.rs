@@ -1,5 +1,6 @@
-use crate::actor::{Actor, ActorContext, Message};
+use crate::actor::{Actor, ActorContext, Message, StopReason};
 use crate::errors::ActorError;
+use crate::identifier::ActorId;
 
 #[async_trait::async_trait]
 pub trait Handler<M: Message>: 'static + Sync + Send
@@ -30,4 +31,13 @@ impl<A: Actor> Handler<Terminate> for A {
         ctx.shutdown().await;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Delivered to the Actors watching another one through [`ActorContext::watch`] once it has stopped.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Terminated {
+    pub id: ActorId,
+    pub reason: StopReason,
+}
+
+impl Message for Terminated {}
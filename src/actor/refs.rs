@@ -1,17 +1,28 @@
-use std::any::Any;
+use std::any::{type_name, Any, TypeId};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
+use tracing::{Instrument, Span};
 
-use crate::actor::{Actor, Handler, Message, Terminate};
+use crate::actor::{Actor, ActorContext, Directive, Handler, Message, StopReason, SupervisorStrategy};
 use crate::errors::ActorError;
+use crate::identifier::ActorId;
+use crate::system::{DeadLetter, DeadLetterReason};
+use self::mailbox::{Delivery, MailboxError, MailboxSender};
 
 mod action;
 mod cell;
+mod mailbox;
+mod recipient;
+mod unwind;
 
 pub use self::action::*;
 pub use self::cell::*;
+pub use self::mailbox::{MailboxConfig, OverflowPolicy};
+pub use self::recipient::*;
+pub(crate) use self::mailbox::channel;
+pub(crate) use self::unwind::CatchUnwind;
 
 pub struct ActorRef<A: Actor> {
     pub(crate) cell: ActorCell,
@@ -21,7 +32,13 @@ pub struct ActorRef<A: Actor> {
 #[async_trait::async_trait]
 impl<A: Actor> DynRef for ActorRef<A> {
     async fn shutdown(&self) -> Result<(), ActorError> {
-        ErrorFlattenAction::ask(self, Terminate).await
+        let (tx, rx) = oneshot::channel();
+        self.enqueue_control(Box::new(Shutdown { oneshot: tx }))?;
+        let Ok(res) = rx.await else {
+            return Err(ActorError::CallBackSend);
+        };
+
+        res
     }
 
     async fn is_active(&self) -> bool {
@@ -43,16 +60,103 @@ impl<A: Actor> Clone for ActorRef<A> {
 }
 
 pub(crate) struct RefContext<A> {
-    pub(crate) sender: UnboundedSender<Box<dyn Applier<A>>>,
+    pub(crate) sender: MailboxSender<Box<dyn Applier<A>>>,
 }
 
 impl<A: Actor> ActorRef<A> {
-    pub(crate) fn new(cell: ActorCell, sender: UnboundedSender<Box<dyn Applier<A>>>) -> ActorRef<A> {
+    pub(crate) fn new(cell: ActorCell, sender: MailboxSender<Box<dyn Applier<A>>>) -> ActorRef<A> {
         Self {
             cell,
             channel: Arc::new(RefContext { sender }),
         }
     }
+    
+    pub(crate) fn id(&self) -> &ActorId {
+        &self.cell.0.id
+    }
+    
+    pub(crate) fn mailbox_len(&self) -> usize {
+        self.channel.sender.len()
+    }
+}
+
+impl<A: Actor> ActorRef<A> {
+    /// Put a message into the mailbox, following its [`OverflowPolicy`] when the mailbox is full.
+    pub(crate) async fn enqueue(&self, payload: Box<dyn Applier<A>>) -> Result<(), ActorError> {
+        let res = self.channel.sender.send(payload).await;
+        self.settle(res)
+    }
+    
+    /// Same as [`ActorRef::enqueue`], but fails instead of waiting for space.
+    pub(crate) fn try_enqueue(&self, payload: Box<dyn Applier<A>>) -> Result<(), ActorError> {
+        let res = self.channel.sender.try_send(payload);
+        self.settle(res)
+    }
+    
+    fn settle(&self, res: Delivery<Box<dyn Applier<A>>>) -> Result<(), ActorError> {
+        match res {
+            Ok(None) => {
+                self.cell.enqueued();
+                Ok(())
+            }
+            Ok(Some(dropped)) => {
+                tracing::warn!("mailbox of actor: {} overflowed, a message was dropped.", self.id());
+                self.cell.undeliverable(dropped, ActorError::MailboxFull { id: self.id().clone() }, DeadLetterReason::MailboxFull);
+                Ok(())
+            }
+            Err(MailboxError::Full(refused)) => {
+                self.cell.undeliverable(refused, ActorError::MailboxFull { id: self.id().clone() }, DeadLetterReason::MailboxFull);
+                Err(ActorError::MailboxFull { id: self.id().clone() })
+            }
+            Err(MailboxError::Closed(refused)) => {
+                self.cell.undeliverable(refused, ActorError::CallBackSend, DeadLetterReason::MailboxClosed);
+                Err(ActorError::CallBackSend)
+            }
+        }
+    }
+    
+    /// Put a message into the mailbox ignoring its capacity, so that control messages are never dropped.
+    pub(crate) fn enqueue_control(&self, payload: Box<dyn Applier<A>>) -> Result<(), ActorError> {
+        if let Err(MailboxError::Full(refused) | MailboxError::Closed(refused)) = self.channel.sender.force(payload) {
+            self.cell.undeliverable(refused, ActorError::CallBackSend, DeadLetterReason::MailboxClosed);
+            return Err(ActorError::CallBackSend);
+        }
+        self.cell.enqueued();
+        Ok(())
+    }
+    
+    /// Enqueue the message and wait for its reply, failing with [`ActorError::Timeout`] once `timeout` has elapsed.
+    async fn request<T: Send>(
+        &self, 
+        payload: Box<dyn Applier<A>>, 
+        rx: oneshot::Receiver<Result<T, ActorError>>, 
+        timeout: Option<Duration>
+    ) -> Result<T, ActorError> {
+        let request = async {
+            self.enqueue(payload).await?;
+            let Ok(res) = rx.await else {
+                return Err(ActorError::CallBackSend);
+            };
+            res
+        };
+        
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, request).await
+                .map_err(|_| ActorError::Timeout { id: self.id().clone() })?,
+            None => request.await
+        }
+    }
+    
+    /// Mark the Actor as stopped for the given reason, bypassing [`Handler<Terminate>`].
+    pub(crate) async fn terminate(&self, reason: StopReason) -> Result<(), ActorError> {
+        let (tx, rx) = oneshot::channel();
+        self.enqueue_control(Box::new(Stop { reason, oneshot: tx }))?;
+        let Ok(res) = rx.await else {
+            return Err(ActorError::CallBackSend);
+        };
+        
+        res
+    }
 }
 
 impl<A: Actor> RegularAction<A> for ActorRef<A> {
@@ -64,17 +168,27 @@ impl<A: Actor> RegularAction<A> for ActorRef<A> {
             A: Handler<M>,
     {
         let (tx, rx) = oneshot::channel();
-        let Ok(_) = self.channel.sender.send(Box::new(Callback {
+        self.request(Box::new(Callback {
             message: msg,
             oneshot: tx,
-        })) else {
-            return Err(ActorError::CallBackSend);
-        };
-        let Ok(res) = rx.await else {
-            return Err(ActorError::CallBackSend);
-        };
+            trace: Trace::capture(),
+        }), rx, self.cell.0.ask_timeout).await
+    }
 
-        Ok(res)
+    async fn ask_timeout<M: Message>(
+        &self,
+        msg: M,
+        timeout: Duration,
+    ) -> Result<Result<A::Accept, A::Rejection>, ActorError>
+        where
+            A: Handler<M>,
+    {
+        let (tx, rx) = oneshot::channel();
+        self.request(Box::new(Callback {
+            message: msg,
+            oneshot: tx,
+            trace: Trace::capture(),
+        }), rx, Some(timeout)).await
     }
 
     async fn tell<M: Message>(&self, msg: M) -> Result<Result<(), A::Rejection>, ActorError>
@@ -82,17 +196,18 @@ impl<A: Actor> RegularAction<A> for ActorRef<A> {
             A: Handler<M>,
     {
         let (tx, rx) = oneshot::channel();
-        let Ok(_) = self.channel.sender.send(Box::new(Void {
+        self.request(Box::new(Void {
             message: msg,
             oneshot: tx,
-        })) else {
-            return Err(ActorError::CallBackSend);
-        };
-        let Ok(res) = rx.await else {
-            return Err(ActorError::CallBackSend);
-        };
-
-        Ok(res)
+            trace: Trace::capture(),
+        }), rx, self.cell.0.ask_timeout).await
+    }
+    
+    fn send<M: Message>(&self, msg: M) -> Result<(), ActorError>
+        where
+            A: Handler<M>,
+    {
+        self.try_enqueue(Box::new(Detached { message: msg, trace: Trace::capture() }))
     }
 }
 
@@ -105,6 +220,14 @@ impl<A: Actor> ErrorFlattenAction<A> for ActorRef<A> {
         RegularAction::ask(self, msg).await.unwrap_or_else(|e| Err(e.into()))
     }
 
+    async fn ask_timeout<M: Message>(&self, msg: M, timeout: Duration) -> Result<A::Accept, A::Rejection>
+        where
+            A: Handler<M>,
+            A::Rejection: From<ActorError>,
+    {
+        RegularAction::ask_timeout(self, msg, timeout).await.unwrap_or_else(|e| Err(e.into()))
+    }
+
     async fn tell<M: Message>(&self, msg: M) -> Result<(), A::Rejection>
         where
             A: Handler<M>,
@@ -114,9 +237,83 @@ impl<A: Actor> ErrorFlattenAction<A> for ActorRef<A> {
     }
 }
 
+/// A type-erased message waiting in the mailbox of an Actor.
+/// 
+/// An `Err` returned from [`Applier::apply`] is treated as a failure of the Actor 
+/// and is handed to its [`SupervisorStrategy`], 
+/// except for [`ActorError::CallBackSend`] which only means the caller has gone away.
 #[async_trait::async_trait]
 pub(crate) trait Applier<A: Actor>: 'static + Sync + Send {
-    async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut A::Context) -> Result<(), ActorError>;
+    /// `supervisor` decides on a rejection of the handler while it is still at hand, 
+    /// before it is handed back to the caller.
+    async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut A::Context, supervisor: &SupervisorStrategy<A>) -> Result<(), Failure>;
+    
+    /// Give up on the message and tell the waiting caller why.
+    /// 
+    /// Returns the message as a [`DeadLetter`] addressed to `target`, or `None` for control messages of the system.
+    fn reject(self: Box<Self>, error: ActorError, target: &ActorId, reason: DeadLetterReason) -> Option<DeadLetter>;
+    
+    /// Type name of the message handed to [`Handler::call`], `None` for control messages of the system.
+    fn message(&self) -> Option<&'static str>;
+    
+    /// Whether the caller has stopped waiting for the result, so the message can be skipped.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+/// Sending half for the result of a message, or the [`ActorError`] that prevented the handler from finishing.
+pub(crate) type Reply<T> = oneshot::Sender<Result<T, ActorError>>;
+
+/// A message whose handling has failed.
+/// 
+/// `directive` is already decided for a rejection of the handler, 
+/// any other failure is decided by the lifecycle from `error`.
+pub(crate) struct Failure {
+    pub(crate) error: ActorError,
+    pub(crate) directive: Option<Directive>,
+}
+
+impl Failure {
+    fn rejected<A, M: Message>(rejection: &A::Rejection, supervisor: &SupervisorStrategy<A>) -> Self
+        where A: Actor + Handler<M>
+    {
+        Self {
+            error: ActorError::Rejected { message: type_name::<M>() },
+            directive: Some(supervisor.decide_rejected::<M>(rejection)),
+        }
+    }
+}
+
+impl From<ActorError> for Failure {
+    fn from(error: ActorError) -> Self {
+        Self { error, directive: None }
+    }
+}
+
+/// The span of the caller at the time a message was enqueued, 
+/// so that a trace continues from the caller into the handler.
+pub(crate) struct Trace {
+    span: Span,
+    enqueued: Instant,
+}
+
+impl Trace {
+    pub(crate) fn capture() -> Self {
+        Self { span: Span::current(), enqueued: Instant::now() }
+    }
+    
+    /// Span of a single [`Handler::call`], child of the caller's span, 
+    /// or of the Actor's lifecycle span if the caller was not traced.
+    fn handle<M: Message>(&self, id: &ActorId) -> Span {
+        tracing::debug_span!(
+            parent: self.span.id().or_else(|| Span::current().id()),
+            "handle",
+            message = type_name::<M>(),
+            actor_id = %id,
+            queue_wait = ?self.enqueued.elapsed(),
+        )
+    }
 }
 
 pub(crate) struct Callback<A: Actor, M: Message>
@@ -124,7 +321,8 @@ where
     A: Handler<M>,
 {
     pub(crate) message: M,
-    pub(crate) oneshot: oneshot::Sender<Result<A::Accept, A::Rejection>>,
+    pub(crate) oneshot: Reply<Result<A::Accept, A::Rejection>>,
+    pub(crate) trace: Trace,
 }
 
 #[async_trait::async_trait]
@@ -132,11 +330,36 @@ impl<A: Actor, M: Message> Applier<A> for Callback<A, M>
 where
     A: Handler<M>,
 {
-    async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut A::Context) -> Result<(), ActorError> {
-        Ok(self
-            .oneshot
-            .send(actor.call(self.message, ctx).await)
-            .map_err(|_| ActorError::CallBackSend)?)
+    async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut A::Context, supervisor: &SupervisorStrategy<A>) -> Result<(), Failure> {
+        let span = self.trace.handle::<M>(ctx.id());
+        let res = match CatchUnwind(actor.call(self.message, ctx).instrument(span)).await {
+            Ok(res) => res,
+            Err(reason) => {
+                let _ = self.oneshot.send(Err(panicked::<M>(&reason)));
+                return Err(panicked::<M>(&reason).into());
+            }
+        };
+        let rejected = res.as_ref().err().map(|rejection| Failure::rejected::<A, M>(rejection, supervisor));
+        let sent = self.oneshot.send(Ok(res));
+        
+        if let Some(failure) = rejected {
+            return Err(failure);
+        }
+        
+        sent.map_err(|_| ActorError::CallBackSend.into())
+    }
+    
+    fn reject(self: Box<Self>, error: ActorError, target: &ActorId, reason: DeadLetterReason) -> Option<DeadLetter> {
+        let _ = self.oneshot.send(Err(error));
+        Some(DeadLetter::new(target.clone(), type_name::<M>(), reason).with_payload(self.message))
+    }
+    
+    fn message(&self) -> Option<&'static str> {
+        Some(type_name::<M>())
+    }
+    
+    fn is_cancelled(&self) -> bool {
+        self.oneshot.is_closed()
     }
 }
 
@@ -145,7 +368,8 @@ where
     A: Handler<M>,
 {
     pub(crate) message: M,
-    pub(crate) oneshot: oneshot::Sender<Result<(), A::Rejection>>,
+    pub(crate) oneshot: Reply<Result<(), A::Rejection>>,
+    pub(crate) trace: Trace,
 }
 
 #[async_trait::async_trait]
@@ -153,18 +377,148 @@ impl<A: Actor, M: Message> Applier<A> for Void<A, M>
 where
     A: Handler<M>,
 {
-    async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut A::Context) -> Result<(), ActorError> {
-        match actor.call(self.message, ctx).await {
-            Ok(_) => self
-                .oneshot
-                .send(Ok(()))
-                .map_err(|_| ActorError::CallBackSend),
-            Err(e) => self
+    async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut A::Context, supervisor: &SupervisorStrategy<A>) -> Result<(), Failure> {
+        let span = self.trace.handle::<M>(ctx.id());
+        match CatchUnwind(actor.call(self.message, ctx).instrument(span)).await {
+            Ok(Ok(_)) => self
                 .oneshot
-                .send(Err(e))
-                .map_err(|_| ActorError::CallBackSend),
+                .send(Ok(Ok(())))
+                .map_err(|_| ActorError::CallBackSend.into()),
+            Ok(Err(e)) => {
+                let failure = Failure::rejected::<A, M>(&e, supervisor);
+                let _ = self.oneshot.send(Ok(Err(e)));
+                Err(failure)
+            },
+            Err(reason) => {
+                let _ = self.oneshot.send(Err(panicked::<M>(&reason)));
+                Err(panicked::<M>(&reason).into())
+            }
         }
     }
+    
+    fn reject(self: Box<Self>, error: ActorError, target: &ActorId, reason: DeadLetterReason) -> Option<DeadLetter> {
+        let _ = self.oneshot.send(Err(error));
+        Some(DeadLetter::new(target.clone(), type_name::<M>(), reason).with_payload(self.message))
+    }
+    
+    fn message(&self) -> Option<&'static str> {
+        Some(type_name::<M>())
+    }
+    
+    fn is_cancelled(&self) -> bool {
+        self.oneshot.is_closed()
+    }
+}
+
+pub(crate) struct Detached<M: Message> {
+    pub(crate) message: M,
+    pub(crate) trace: Trace,
+}
+
+#[async_trait::async_trait]
+impl<A: Actor, M: Message> Applier<A> for Detached<M>
+where
+    A: Handler<M>,
+{
+    async fn apply(self: Box<Self>, actor: &mut A, ctx: &mut A::Context, supervisor: &SupervisorStrategy<A>) -> Result<(), Failure> {
+        let span = self.trace.handle::<M>(ctx.id());
+        let (failure, reason) = match CatchUnwind(actor.call(self.message, ctx).instrument(span)).await {
+            Ok(Ok(_)) => return Ok(()),
+            Ok(Err(rejection)) => (Failure::rejected::<A, M>(&rejection, supervisor), DeadLetterReason::Rejected),
+            Err(reason) => (panicked::<M>(&reason).into(), DeadLetterReason::Panicked(reason)),
+        };
+        
+        ctx.system()
+            .dead_letters()
+            .publish(DeadLetter::new(ctx.id().clone(), type_name::<M>(), reason));
+        
+        Err(failure)
+    }
+    
+    fn reject(self: Box<Self>, _: ActorError, target: &ActorId, reason: DeadLetterReason) -> Option<DeadLetter> {
+        Some(DeadLetter::new(target.clone(), type_name::<M>(), reason).with_payload(self.message))
+    }
+    
+    fn message(&self) -> Option<&'static str> {
+        Some(type_name::<M>())
+    }
+}
+
+pub(crate) struct Stop {
+    pub(crate) reason: StopReason,
+    pub(crate) oneshot: Reply<()>,
+}
+
+#[async_trait::async_trait]
+impl<A: Actor> Applier<A> for Stop {
+    async fn apply(self: Box<Self>, _: &mut A, ctx: &mut A::Context, _: &SupervisorStrategy<A>) -> Result<(), Failure> {
+        ctx.state().stop(self.reason).await;
+        self.oneshot
+            .send(Ok(()))
+            .map_err(|_| ActorError::CallBackSend.into())
+    }
+    
+    fn reject(self: Box<Self>, error: ActorError, _: &ActorId, _: DeadLetterReason) -> Option<DeadLetter> {
+        let _ = self.oneshot.send(Err(error));
+        None
+    }
+    
+    fn message(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Shutdown requested through [`DynRef::shutdown`], 
+/// applied like [`Terminate`](crate::actor::Terminate) but as a control message so that no interceptor can refuse it.
+pub(crate) struct Shutdown {
+    pub(crate) oneshot: Reply<()>,
+}
+
+#[async_trait::async_trait]
+impl<A: Actor> Applier<A> for Shutdown {
+    async fn apply(self: Box<Self>, _: &mut A, ctx: &mut A::Context, _: &SupervisorStrategy<A>) -> Result<(), Failure> {
+        tracing::warn!("received terminate signal.");
+        ctx.shutdown().await;
+        self.oneshot
+            .send(Ok(()))
+            .map_err(|_| ActorError::CallBackSend.into())
+    }
+    
+    fn reject(self: Box<Self>, error: ActorError, _: &ActorId, _: DeadLetterReason) -> Option<DeadLetter> {
+        let _ = self.oneshot.send(Err(error));
+        None
+    }
+    
+    fn message(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// A failure a child Actor has handed over to the supervisor of its parent.
+pub(crate) struct Escalation {
+    pub(crate) child: ActorId,
+    pub(crate) error: ActorError,
+}
+
+#[async_trait::async_trait]
+impl<A: Actor> Applier<A> for Escalation {
+    async fn apply(self: Box<Self>, _: &mut A, _: &mut A::Context, _: &SupervisorStrategy<A>) -> Result<(), Failure> {
+        tracing::error!("child actor: {} escalated a failure.", self.child);
+        Err(self.error.into())
+    }
+    
+    fn reject(self: Box<Self>, error: ActorError, _: &ActorId, _: DeadLetterReason) -> Option<DeadLetter> {
+        tracing::warn!("failure escalated from child actor: {} was discarded: {}", self.child, error);
+        None
+    }
+    
+    fn message(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+fn panicked<M: Message>(reason: &str) -> ActorError {
+    ActorError::Panicked { message: type_name::<M>(), reason: reason.to_string() }
 }
 
 #[async_trait::async_trait]
@@ -181,16 +535,88 @@ pub trait DynRef: Any {
     fn as_any(&self) -> &dyn Any;
 }
 
-pub struct AnyRef(Arc<dyn DynRef + Sync + Send>);
+/// Operations the system needs on an Actor without knowing its type.
+#[async_trait::async_trait]
+pub(crate) trait ErasedRef: DynRef + Sync + Send {
+    fn cell(&self) -> &ActorCell;
+    async fn terminate(&self, reason: StopReason) -> Result<(), ActorError>;
+    fn escalate(&self, child: ActorId, error: ActorError) -> Result<(), ActorError>;
+    fn actor_type(&self) -> TypeId;
+    fn type_name(&self) -> &'static str;
+}
+
+#[async_trait::async_trait]
+impl<A: Actor> ErasedRef for ActorRef<A> {
+    fn cell(&self) -> &ActorCell {
+        &self.cell
+    }
+    
+    async fn terminate(&self, reason: StopReason) -> Result<(), ActorError> {
+        ActorRef::terminate(self, reason).await
+    }
+    
+    fn escalate(&self, child: ActorId, error: ActorError) -> Result<(), ActorError> {
+        self.enqueue_control(Box::new(Escalation { child, error }))
+    }
+    
+    fn actor_type(&self) -> TypeId {
+        TypeId::of::<A>()
+    }
+    
+    fn type_name(&self) -> &'static str {
+        type_name::<A>()
+    }
+}
+
+pub struct AnyRef(Arc<dyn ErasedRef>);
 
 impl AnyRef {
+    pub fn id(&self) -> &ActorId {
+        &self.0.cell().0.id
+    }
+    
+    pub(crate) fn cell(&self) -> &ActorCell {
+        self.0.cell()
+    }
+    
+    pub(crate) fn escalate(&self, child: ActorId, error: ActorError) -> Result<(), ActorError> {
+        self.0.escalate(child, error)
+    }
+    
+    pub(crate) async fn terminate(&self, reason: StopReason) -> Result<(), ActorError> {
+        self.0.terminate(reason).await
+    }
+    
+    pub(crate) async fn terminated(&self) {
+        self.0.cell().terminated().await
+    }
+    
+    /// Whether the referenced Actor is of type `A`.
+    pub fn is<A: Actor>(&self) -> bool {
+        self.0.actor_type() == TypeId::of::<A>()
+    }
+    
+    /// Name of the referenced Actor's type, as given by [`std::any::type_name`].
+    pub fn type_name(&self) -> &'static str {
+        self.0.type_name()
+    }
+    
+    pub(crate) fn actor_type(&self) -> TypeId {
+        self.0.actor_type()
+    }
+    
+    /// Fails with [`ActorError::TypeMismatch`] if the referenced Actor is not of type `A`.
     pub fn downcast<A: Actor>(self) -> Result<ActorRef<A>, ActorError> {
         self
             .0
             .as_any()
             .downcast_ref::<ActorRef<A>>()
             .cloned()
-            .ok_or_else(|| ActorError::DownCastFromAny)
+            .ok_or_else(|| ActorError::TypeMismatch { 
+                id: self.id().clone(), 
+                expected: type_name::<A>(), 
+                actual: self.type_name() 
+            })
     }
 }
 
@@ -1,6 +1,7 @@
 //! The `action` module is used to modify the conduct of [`ActorRef`](crate::actor::refs::ActorRef).
 
 use std::future::Future;
+use std::time::Duration;
 use crate::actor::{Actor, Handler, Message};
 use crate::errors::ActorError;
 
@@ -8,8 +9,21 @@ pub trait RegularAction<A: Actor>: 'static + Sync + Send {
     fn ask<M: Message>(&self, msg: M) -> impl Future<Output=Result<Result<A::Accept, A::Rejection>, ActorError>> + Send
         where A: Handler<M>;
 
+    /// Same as [`RegularAction::ask`], but gives up with [`ActorError::Timeout`] once `timeout` has elapsed.
+    /// 
+    /// A message given up on is skipped by the Actor if it has not been handled yet.
+    fn ask_timeout<M: Message>(&self, msg: M, timeout: Duration) -> impl Future<Output=Result<Result<A::Accept, A::Rejection>, ActorError>> + Send
+        where A: Handler<M>;
+
     fn tell<M: Message>(&self, msg: M) -> impl Future<Output=Result<Result<(), A::Rejection>, ActorError>> + Send
         where A: Handler<M>;
+
+    /// Enqueue the message and return immediately without waiting for the handler.
+    /// 
+    /// A failed result is reported to the [`DeadLetterListener`](crate::system::DeadLetterListener) of the system.
+    /// A full bounded mailbox fails with [`ActorError::MailboxFull`] even under [`OverflowPolicy::Wait`](crate::actor::refs::OverflowPolicy::Wait).
+    fn send<M: Message>(&self, msg: M) -> Result<(), ActorError>
+        where A: Handler<M>;
 }
 
 pub trait ErrorFlattenAction<A: Actor>: 'static + Sync + Send {
@@ -17,6 +31,10 @@ pub trait ErrorFlattenAction<A: Actor>: 'static + Sync + Send {
         where A: Handler<M>,
               A::Rejection: From<ActorError>;
 
+    fn ask_timeout<M: Message>(&self, msg: M, timeout: Duration) -> impl Future<Output=Result<A::Accept, A::Rejection>> + Send
+        where A: Handler<M>,
+              A::Rejection: From<ActorError>;
+
     fn tell<M: Message>(&self, msg: M) -> impl Future<Output=Result<(), A::Rejection>> + Send
         where A: Handler<M>,
               A::Rejection: From<ActorError>;
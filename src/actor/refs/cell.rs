@@ -1,14 +1,169 @@
-use std::sync::Arc;
-use crate::actor::RunningState;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::watch;
+use crate::actor::{RunningState, StopReason, Terminated};
+use crate::actor::Actor;
+use crate::actor::refs::{AnyRef, Applier};
+use crate::errors::ActorError;
+use crate::identifier::ActorId;
+use crate::system::{Activity, ActorMetrics, DeadLetterReason, DeadLetters};
 
 pub struct ActorCell(pub(crate) Arc<InnerCell>);
 
 pub(crate) struct InnerCell {
-    pub(crate) running_state: RunningState
+    pub(crate) id: ActorId,
+    pub(crate) running_state: RunningState,
+    pub(crate) terminated: watch::Receiver<bool>,
+    pub(crate) ask_timeout: Option<Duration>,
+    pub(crate) parent: Option<AnyRef>,
+    pub(crate) children: Mutex<Vec<AnyRef>>,
+    pub(crate) watchers: Mutex<Watchers>,
+    /// Actors this one watches, so that it stops watching them once it has stopped itself.
+    pub(crate) watching: Mutex<Vec<Weak<InnerCell>>>,
+    pub(crate) metrics: Option<Arc<ActorMetrics>>,
+    pub(crate) dead_letters: DeadLetters,
+    pub(crate) activity: Option<Activity>,
+}
+
+pub(crate) type Notify = Box<dyn FnOnce(Terminated) + Sync + Send>;
+
+pub(crate) enum Watchers {
+    Watching(Vec<(ActorCell, Notify)>),
+    Stopped(StopReason),
+}
+
+impl Default for Watchers {
+    fn default() -> Self {
+        Self::Watching(Vec::new())
+    }
+}
+
+impl ActorCell {
+    /// Wait until the lifecycle of the Actor has completely finished.
+    pub(crate) async fn terminated(&self) {
+        let mut terminated = self.0.terminated.clone();
+        // If the lifecycle has gone away without notifying, it has finished as well.
+        let _ = terminated.wait_for(|terminated| *terminated).await;
+    }
+    
+    /// Metrics of the Actor type, `None` unless [`Metrics`](crate::system::Metrics) is installed.
+    pub(crate) fn metrics(&self) -> Option<&ActorMetrics> {
+        self.0.metrics.as_deref()
+    }
+    
+    /// A message has been put into the mailbox.
+    pub(crate) fn enqueued(&self) {
+        if let Some(metrics) = self.metrics() {
+            metrics.enqueued();
+        }
+        self.begin_work();
+    }
+    
+    pub(crate) fn begin_work(&self) {
+        if let Some(activity) = &self.0.activity {
+            activity.begin();
+        }
+    }
+    
+    pub(crate) fn end_work(&self, count: usize) {
+        if let Some(activity) = &self.0.activity {
+            activity.end(count);
+        }
+    }
+    
+    /// Give up on a message that will never be handled, telling its caller `error` and reporting it as a dead letter.
+    pub(crate) fn undeliverable<A: Actor>(&self, payload: Box<dyn Applier<A>>, error: ActorError, reason: DeadLetterReason) {
+        if let Some(letter) = payload.reject(error, &self.0.id, reason) {
+            self.0.dead_letters.publish(letter);
+        }
+    }
+    
+    pub(crate) fn is(&self, other: &ActorCell) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+    
+    pub(crate) fn parent(&self) -> Option<&AnyRef> {
+        self.0.parent.as_ref()
+    }
+    
+    pub(crate) fn children(&self) -> Vec<AnyRef> {
+        self.lock_children().clone()
+    }
+    
+    pub(crate) fn adopt(&self, child: AnyRef) {
+        self.lock_children().push(child);
+    }
+    
+    pub(crate) fn abandon(&self, child: &ActorCell) {
+        self.lock_children().retain(|adopted| !adopted.cell().is(child));
+    }
+    
+    /// Notify `watcher` once the Actor has stopped, immediately if it already has.
+    /// 
+    /// The watch is dropped as well when `watcher` stops first, see [`ActorCell::unwatch_all`].
+    pub(crate) fn watched_by(&self, watcher: &ActorCell, notify: Notify) {
+        let mut watchers = self.lock_watchers();
+        match &mut *watchers {
+            Watchers::Watching(watching) => {
+                watching.retain(|(watched_by, _)| !watched_by.is(watcher));
+                watching.push((watcher.clone(), notify));
+                
+                let mut targets = watcher.lock_watching();
+                targets.retain(|target| !target.ptr_eq(&Arc::downgrade(&self.0)));
+                targets.push(Arc::downgrade(&self.0));
+            }
+            Watchers::Stopped(reason) => {
+                let reason = *reason;
+                drop(watchers);
+                notify(Terminated { id: self.0.id.clone(), reason });
+            }
+        }
+    }
+    
+    pub(crate) fn unwatched_by(&self, watcher: &ActorCell) {
+        if let Watchers::Watching(watching) = &mut *self.lock_watchers() {
+            watching.retain(|(watched_by, _)| !watched_by.is(watcher));
+        }
+        watcher.forget(self);
+    }
+    
+    pub(crate) fn notify_watchers(&self, reason: StopReason) {
+        let watchers = std::mem::replace(&mut *self.lock_watchers(), Watchers::Stopped(reason));
+        if let Watchers::Watching(watching) = watchers {
+            for (watcher, notify) in watching {
+                watcher.forget(self);
+                notify(Terminated { id: self.0.id.clone(), reason });
+            }
+        }
+    }
+    
+    /// Stop watching every Actor, so that they no longer keep this stopped Actor alive through their watchers.
+    pub(crate) fn unwatch_all(&self) {
+        let targets = std::mem::take(&mut *self.lock_watching());
+        for target in targets.iter().filter_map(Weak::upgrade) {
+            ActorCell(target).unwatched_by(self);
+        }
+    }
+    
+    fn forget(&self, target: &ActorCell) {
+        self.lock_watching().retain(|watched| !watched.ptr_eq(&Arc::downgrade(&target.0)));
+    }
+    
+    fn lock_watching(&self) -> std::sync::MutexGuard<'_, Vec<Weak<InnerCell>>> {
+        self.0.watching.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    fn lock_watchers(&self) -> std::sync::MutexGuard<'_, Watchers> {
+        self.0.watchers.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    fn lock_children(&self) -> std::sync::MutexGuard<'_, Vec<AnyRef>> {
+        self.0.children.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
 }
 
 impl Clone for ActorCell {
     fn clone(&self) -> Self {
         Self(Arc::clone(&self.0))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,235 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use tokio::sync::Notify;
+
+/// Decides what happens to a message sent to a bounded mailbox that is already full.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OverflowPolicy {
+    /// Wait until the Actor has taken a message out of the mailbox.
+    Wait,
+    /// Reject the message immediately with [`ActorError::MailboxFull`](crate::errors::ActorError::MailboxFull).
+    Fail,
+    /// Discard the message that was just sent.
+    DropNewest,
+    /// Discard the oldest message waiting in the mailbox to make room.
+    DropOldest,
+}
+
+/// Capacity of the mailbox of an Actor, set through [`SpawnConfig::mailbox`](crate::system::SpawnConfig::mailbox).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MailboxConfig {
+    capacity: Option<usize>,
+    overflow: OverflowPolicy,
+}
+
+impl MailboxConfig {
+    pub fn unbounded() -> Self {
+        Self { capacity: None, overflow: OverflowPolicy::Wait }
+    }
+
+    /// A mailbox holding at most `capacity` messages, which is at least one.
+    pub fn bounded(capacity: usize, overflow: OverflowPolicy) -> Self {
+        Self { capacity: Some(capacity.max(1)), overflow }
+    }
+}
+
+impl Default for MailboxConfig {
+    fn default() -> Self {
+        Self::unbounded()
+    }
+}
+
+pub(crate) enum MailboxError<T> {
+    Full(T),
+    Closed(T),
+}
+
+/// Outcome of enqueueing a message, holding the message discarded to keep the capacity, if any.
+pub(crate) type Delivery<T> = Result<Option<T>, MailboxError<T>>;
+
+pub(crate) fn channel<T>(config: MailboxConfig) -> (MailboxSender<T>, MailboxReceiver<T>) {
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(Queue {
+            items: VecDeque::new(),
+            counted: 0,
+            closed: false,
+            disconnected: false,
+        }),
+        config,
+        receivable: Notify::new(),
+        space: Notify::new(),
+    });
+
+    (MailboxSender(Arc::clone(&shared)), MailboxReceiver(shared))
+}
+
+struct Shared<T> {
+    queue: Mutex<Queue<T>>,
+    config: MailboxConfig,
+    receivable: Notify,
+    space: Notify,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, Queue<T>> {
+        self.queue.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+struct Queue<T> {
+    items: VecDeque<Envelope<T>>,
+    /// Number of queued messages that count against the capacity.
+    counted: usize,
+    /// The receiver has been dropped.
+    closed: bool,
+    /// The sender has been dropped.
+    disconnected: bool,
+}
+
+struct Envelope<T> {
+    item: T,
+    counted: bool,
+}
+
+impl<T> Queue<T> {
+    fn push(&mut self, item: T, counted: bool) {
+        if counted {
+            self.counted += 1;
+        }
+        self.items.push_back(Envelope { item, counted });
+    }
+}
+
+pub(crate) struct MailboxSender<T>(Arc<Shared<T>>);
+
+impl<T> MailboxSender<T> {
+    /// Enqueue a message according to the [`OverflowPolicy`].
+    pub async fn send(&self, mut item: T) -> Delivery<T> {
+        loop {
+            let space = self.0.space.notified();
+            tokio::pin!(space);
+            space.as_mut().enable();
+
+            match self.offer(item) {
+                Err(MailboxError::Full(rejected)) if self.0.config.overflow == OverflowPolicy::Wait => {
+                    item = rejected;
+                }
+                settled => return settled,
+            }
+
+            space.await;
+        }
+    }
+
+    /// Number of messages waiting in the mailbox.
+    pub fn len(&self) -> usize {
+        self.0.lock().items.len()
+    }
+
+    /// Same as [`MailboxSender::send`], except that [`OverflowPolicy::Wait`] fails instead of waiting.
+    pub fn try_send(&self, item: T) -> Delivery<T> {
+        self.offer(item)
+    }
+
+    /// Enqueue a message regardless of the capacity, used for control messages such as shutdown.
+    pub fn force(&self, item: T) -> Result<(), MailboxError<T>> {
+        let mut queue = self.0.lock();
+        if queue.closed {
+            return Err(MailboxError::Closed(item));
+        }
+        queue.push(item, false);
+        self.0.receivable.notify_one();
+        Ok(())
+    }
+
+    fn offer(&self, item: T) -> Delivery<T> {
+        let Some(capacity) = self.0.config.capacity else {
+            return self.force(item).map(|_| None);
+        };
+
+        let mut queue = self.0.lock();
+        if queue.closed {
+            return Err(MailboxError::Closed(item));
+        }
+
+        if queue.counted < capacity {
+            queue.push(item, true);
+            self.0.receivable.notify_one();
+            return Ok(None);
+        }
+
+        match self.0.config.overflow {
+            OverflowPolicy::Wait | OverflowPolicy::Fail => Err(MailboxError::Full(item)),
+            OverflowPolicy::DropNewest => Ok(Some(item)),
+            OverflowPolicy::DropOldest => {
+                let Some(oldest) = queue.items.iter().position(|envelope| envelope.counted) else {
+                    return Ok(Some(item));
+                };
+                let evicted = queue.items.remove(oldest).map(|envelope| envelope.item);
+                queue.items.push_back(Envelope { item, counted: true });
+                self.0.receivable.notify_one();
+                Ok(evicted)
+            }
+        }
+    }
+}
+
+impl<T> Drop for MailboxSender<T> {
+    fn drop(&mut self) {
+        self.0.lock().disconnected = true;
+        self.0.receivable.notify_one();
+    }
+}
+
+pub(crate) struct MailboxReceiver<T>(Arc<Shared<T>>);
+
+impl<T> MailboxReceiver<T> {
+    /// Receive the next message, or `None` once the sender has gone and the mailbox is empty.
+    pub async fn recv(&mut self) -> Option<T> {
+        loop {
+            let receivable = self.0.receivable.notified();
+            tokio::pin!(receivable);
+            receivable.as_mut().enable();
+
+            {
+                let mut queue = self.0.lock();
+                if let Some(envelope) = queue.items.pop_front() {
+                    if envelope.counted {
+                        queue.counted -= 1;
+                        self.0.space.notify_one();
+                    }
+                    return Some(envelope.item);
+                }
+
+                if queue.disconnected {
+                    return None;
+                }
+            }
+
+            receivable.await;
+        }
+    }
+}
+
+impl<T> MailboxReceiver<T> {
+    /// Refuse further messages and take out the waiting ones.
+    pub fn close(&mut self) -> Vec<T> {
+        let remains = {
+            let mut queue = self.0.lock();
+            queue.closed = true;
+            queue.counted = 0;
+            std::mem::take(&mut queue.items)
+        };
+        self.0.space.notify_waiters();
+        remains.into_iter()
+            .map(|envelope| envelope.item)
+            .collect()
+    }
+}
+
+impl<T> Drop for MailboxReceiver<T> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
@@ -0,0 +1,136 @@
+use std::any::type_name;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::actor::{Actor, Handler, Message};
+use crate::actor::refs::{ActorRef, RegularAction};
+use crate::errors::ActorError;
+use crate::identifier::ActorId;
+
+/// Reference to any Actor handling `M`, erasing the type of the Actor.
+/// 
+/// `T` and `E` are the [`Handler::Accept`] and [`Handler::Rejection`] of the Actor, 
+/// so Actors answering `M` with `Result<(), ActorError>` can share a single `Recipient<M>`.
+/// Actors answering differently are brought to the same type with [`Recipient::erase`].
+/// 
+/// ```ignore
+/// let subscribers: Vec<Recipient<OrderPlaced>> = vec![mailer.recipient(), ledger.into(), audit.recipient().erase()];
+/// ```
+pub struct Recipient<M: Message, T: 'static + Sync + Send = (), E: 'static + Sync + Send = ActorError>(Arc<dyn Deliver<M, T, E>>);
+
+impl<M: Message, T: 'static + Sync + Send, E: 'static + Sync + Send> Recipient<M, T, E> {
+    pub fn id(&self) -> &ActorId {
+        self.0.id()
+    }
+    
+    /// See [`RegularAction::ask`].
+    pub async fn ask(&self, msg: M) -> Result<Result<T, E>, ActorError> {
+        self.0.ask(msg, None).await
+    }
+    
+    /// See [`RegularAction::ask_timeout`].
+    pub async fn ask_timeout(&self, msg: M, timeout: Duration) -> Result<Result<T, E>, ActorError> {
+        self.0.ask(msg, Some(timeout)).await
+    }
+    
+    /// See [`RegularAction::tell`].
+    pub async fn tell(&self, msg: M) -> Result<Result<(), E>, ActorError> {
+        self.0.tell(msg).await
+    }
+    
+    /// See [`RegularAction::send`].
+    pub fn send(&self, msg: M) -> Result<(), ActorError> {
+        self.0.send(msg)
+    }
+    
+    /// Discards the reply of the Actor, so it can be stored next to any other `Recipient<M>`.
+    /// 
+    /// A rejection is reported as [`ActorError::Rejected`].
+    pub fn erase(self) -> Recipient<M> {
+        Recipient::new(Erased(self.0))
+    }
+}
+
+impl<M: Message, T: 'static + Sync + Send, E: 'static + Sync + Send> Clone for Recipient<M, T, E> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<A: Actor, M: Message> From<ActorRef<A>> for Recipient<M, A::Accept, A::Rejection>
+    where A: Handler<M>
+{
+    fn from(value: ActorRef<A>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<A: Actor> ActorRef<A> {
+    pub fn recipient<M: Message>(&self) -> Recipient<M, A::Accept, A::Rejection>
+        where A: Handler<M>
+    {
+        Recipient::from(self.clone())
+    }
+}
+
+impl<M: Message, T: 'static + Sync + Send, E: 'static + Sync + Send> Recipient<M, T, E> {
+    pub(crate) fn new(deliver: impl Deliver<M, T, E>) -> Self {
+        Self(Arc::new(deliver))
+    }
+}
+
+#[async_trait::async_trait]
+pub(crate) trait Deliver<M: Message, T, E>: 'static + Sync + Send {
+    fn id(&self) -> &ActorId;
+    async fn ask(&self, msg: M, timeout: Option<Duration>) -> Result<Result<T, E>, ActorError>;
+    async fn tell(&self, msg: M) -> Result<Result<(), E>, ActorError>;
+    fn send(&self, msg: M) -> Result<(), ActorError>;
+}
+
+#[async_trait::async_trait]
+impl<A: Actor, M: Message> Deliver<M, A::Accept, A::Rejection> for ActorRef<A>
+    where A: Handler<M>
+{
+    fn id(&self) -> &ActorId {
+        ActorRef::id(self)
+    }
+    
+    async fn ask(&self, msg: M, timeout: Option<Duration>) -> Result<Result<A::Accept, A::Rejection>, ActorError> {
+        match timeout {
+            Some(timeout) => RegularAction::ask_timeout(self, msg, timeout).await,
+            None => RegularAction::ask(self, msg).await,
+        }
+    }
+    
+    async fn tell(&self, msg: M) -> Result<Result<(), A::Rejection>, ActorError> {
+        RegularAction::tell(self, msg).await
+    }
+    
+    fn send(&self, msg: M) -> Result<(), ActorError> {
+        RegularAction::send(self, msg)
+    }
+}
+
+struct Erased<M: Message, T, E>(Arc<dyn Deliver<M, T, E>>);
+
+#[async_trait::async_trait]
+impl<M: Message, T: 'static + Sync + Send, E: 'static + Sync + Send> Deliver<M, (), ActorError> for Erased<M, T, E> {
+    fn id(&self) -> &ActorId {
+        self.0.id()
+    }
+    
+    async fn ask(&self, msg: M, timeout: Option<Duration>) -> Result<Result<(), ActorError>, ActorError> {
+        Ok(self.0.ask(msg, timeout).await?
+            .map(|_| ())
+            .map_err(|_| ActorError::Rejected { message: type_name::<M>() }))
+    }
+    
+    async fn tell(&self, msg: M) -> Result<Result<(), ActorError>, ActorError> {
+        Ok(self.0.tell(msg).await?
+            .map_err(|_| ActorError::Rejected { message: type_name::<M>() }))
+    }
+    
+    fn send(&self, msg: M) -> Result<(), ActorError> {
+        self.0.send(msg)
+    }
+}
@@ -0,0 +1,31 @@
+use std::any::Any;
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Future that converts a panic raised while polling the inner future into an `Err`.
+pub(crate) struct CatchUnwind<F>(pub(crate) F);
+
+impl<F: Future + Unpin> Future for CatchUnwind<F> {
+    type Output = Result<F::Output, String>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = &mut self.0;
+        match catch_unwind(AssertUnwindSafe(|| Pin::new(inner).poll(cx))) {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => Poll::Ready(Err(reason(payload))),
+        }
+    }
+}
+
+fn reason(payload: Box<dyn Any + Send>) -> String {
+    if let Some(reason) = payload.downcast_ref::<&'static str>() {
+        return reason.to_string();
+    }
+
+    match payload.downcast::<String>() {
+        Ok(reason) => *reason,
+        Err(_) => "unknown panic payload".to_string(),
+    }
+}
@@ -1,40 +1,119 @@
 use std::future::Future;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
+use crate::actor::refs::AnyRef;
 
-pub struct RunningState(Arc<RwLock<State>>);
+/// Whether the Actor is running, shared between its context and its references.
+pub struct RunningState {
+    state: Arc<RwLock<State>>,
+    myself: Arc<Mutex<Option<AnyRef>>>,
+}
 
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) enum State {
     Active,
-    Shutdown
+    Shutdown(StopReason)
+}
+
+/// Why an Actor has stopped, passed to [`Actor::deactivate`](crate::actor::Actor::deactivate).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum StopReason {
+    /// Terminated by the user, e.g. through [`Terminate`](crate::actor::Terminate) or [`ActorContext::shutdown`](crate::actor::ActorContext::shutdown).
+    Terminated,
+    /// The whole [`ActorSystem`](crate::system::ActorSystem) is shutting down.
+    SystemShutdown,
+    /// Stopped by its [`SupervisorStrategy`](crate::actor::SupervisorStrategy) after a failure.
+    Supervisor,
+    /// The mailbox has closed without a stop being requested.
+    /// 
+    /// The registry and the Actor's own context hold a sender of the mailbox until its lifecycle ends, 
+    /// so this is only a fallback and is not reported to running Actors in practice.
+    ChannelClosed,
+    /// Stopped along with its parent Actor.
+    Parent,
+    /// Stopped after being idle for too long.
+    Passivated,
+    /// Replaced by a fresh instance through [`Directive::Restart`](crate::actor::Directive::Restart) after a failure.
+    /// 
+    /// Only the failed instance is deactivated, the Actor keeps running with the same context and mailbox.
+    Restarted,
 }
 
 impl RunningState {
     pub(crate) async fn switch<Fut>(&self, f: impl Fn(Arc<RwLock<State>>) -> Fut) 
         where Fut: Future<Output=()> + 'static + Sync + Send
     {
-        f(Arc::clone(&self.0)).await;
+        f(Arc::clone(&self.state)).await;
+    }
+    
+    pub(crate) async fn stop(&self, reason: StopReason) {
+        let mut write = self.state.write().await;
+        if write.eq(&State::Active) {
+            *write = State::Shutdown(reason);
+        }
+    }
+    
+    pub(crate) async fn reason(&self) -> Option<StopReason> {
+        match *self.state.read().await {
+            State::Active => None,
+            State::Shutdown(reason) => Some(reason)
+        }
     }
     
     pub async fn is_active(&self) -> bool {
-        let read = self.0.read().await;
+        let read = self.state.read().await;
         read.eq(&State::Active) 
     }
     
+    /// Same as [`RunningState::is_active`] without waiting, 
+    /// considering the Actor active while its state is being switched.
+    pub(crate) fn is_active_now(&self) -> bool {
+        self.state.try_read()
+            .map(|read| read.eq(&State::Active))
+            .unwrap_or(true)
+    }
+    
     pub async fn available_shutdown(&self) -> bool {
         !self.is_active().await
     }
+    
+    /// Reference to the Actor owning this state, see [`ActorContext::myself`](crate::actor::ActorContext::myself).
+    pub(crate) fn myself(&self) -> Option<AnyRef> {
+        self.lock_myself().clone()
+    }
+    
+    /// Bind the reference handed out by [`RunningState::myself`].
+    /// 
+    /// It holds a sender of the mailbox, so the mailbox stays open until [`RunningState::unbind`].
+    pub(crate) fn bind(&self, myself: AnyRef) {
+        *self.lock_myself() = Some(myself);
+    }
+    
+    /// Release the reference bound by [`RunningState::bind`], 
+    /// which keeps the Actor alive through its own state until its lifecycle has finished.
+    pub(crate) fn unbind(&self) {
+        self.lock_myself().take();
+    }
+    
+    fn lock_myself(&self) -> std::sync::MutexGuard<'_, Option<AnyRef>> {
+        self.myself.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
 }
 
 impl Clone for RunningState {
     fn clone(&self) -> Self {
-        Self(Arc::clone(&self.0))
+        Self { 
+            state: Arc::clone(&self.state), 
+            myself: Arc::clone(&self.myself),
+        }
     }
 }
 
 impl Default for RunningState {
     fn default() -> Self {
-        Self(Arc::new(RwLock::new(State::Active)))
+        Self { 
+            state: Arc::new(RwLock::new(State::Active)), 
+            myself: Default::default(),
+        }
     }
 }
\ No newline at end of file
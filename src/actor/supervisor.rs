@@ -0,0 +1,148 @@
+use std::any::{type_name, Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::actor::{Actor, Handler, Message};
+use crate::errors::ActorError;
+
+type Decider = Arc<dyn Fn(&ActorError) -> Directive + Sync + Send>;
+
+type RejectionDecider = Arc<dyn Fn(&dyn Any) -> Option<Directive> + Sync + Send>;
+
+/// The action the lifecycle takes after a message failed to be applied to an Actor.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Directive {
+    /// Keep the current Actor and continue processing the next message.
+    Resume,
+    /// Discard the current Actor and replace it with a fresh one created by the factory.
+    Restart,
+    /// Stop the Actor and remove it from the registry.
+    Stop,
+    /// Hand the failure over to the parent Actor.
+    Escalate,
+}
+
+/// Policy deciding how an Actor recovers from a failed message.
+///
+/// The strategy can be selected per actor type through [`Actor::supervisor`]
+/// or per spawn through [`SpawnConfig::supervisor`](crate::system::SpawnConfig::supervisor).
+pub struct SupervisorStrategy<A: Actor> {
+    decider: Decider,
+    rejections: HashMap<TypeId, RejectionDecider>,
+    factory: Option<Arc<dyn Fn() -> A + Sync + Send>>,
+}
+
+impl<A: Actor> SupervisorStrategy<A> {
+    pub fn resume() -> Self {
+        Self::directive(Directive::Resume)
+    }
+
+    pub fn stop() -> Self {
+        Self::directive(Directive::Stop)
+    }
+
+    pub fn escalate() -> Self {
+        Self::directive(Directive::Escalate)
+    }
+
+    /// Restart the Actor with a fresh instance created by `factory`.
+    ///
+    /// The fresh Actor is activated again with the same context before it receives any message.
+    pub fn restart<F>(factory: F) -> Self
+        where F: Fn() -> A + 'static + Sync + Send
+    {
+        Self {
+            decider: Arc::new(|_| Directive::Restart),
+            rejections: HashMap::new(),
+            factory: Some(Arc::new(factory)),
+        }
+    }
+
+    /// Replace the decision with one based on the failure.
+    ///
+    /// **note**: Returning [`Directive::Restart`] from a strategy without a factory stops the Actor.
+    pub fn decide_with<F>(mut self, decider: F) -> Self
+        where F: Fn(&ActorError) -> Directive + 'static + Sync + Send
+    {
+        self.decider = Arc::new(decider);
+        self
+    }
+
+    /// Decide on the rejections of `M` by looking at the rejection itself, 
+    /// ahead of the decision given to [`SupervisorStrategy::decide_with`] which only sees [`ActorError::Rejected`].
+    ///
+    /// ```ignore
+    /// SupervisorStrategy::restart(Account::default)
+    ///     .decide_rejection::<Withdraw, _>(|rejection| match rejection {
+    ///         AccountError::Corrupted => Directive::Restart,
+    ///         _ => Directive::Resume,
+    ///     })
+    /// ```
+    pub fn decide_rejection<M: Message, F>(mut self, decider: F) -> Self
+        where
+            A: Handler<M>,
+            F: Fn(&<A as Handler<M>>::Rejection) -> Directive + 'static + Sync + Send
+    {
+        self.rejections.insert(TypeId::of::<M>(), Arc::new(move |rejection| rejection.downcast_ref().map(&decider)));
+        self
+    }
+
+    /// Restart with a fresh instance from `factory` after a panic, 
+    /// leaving every other failure to the decisions of this strategy.
+    pub(crate) fn restart_on_panic(self, factory: Arc<dyn Fn() -> A + Sync + Send>) -> Self {
+        let decider = self.decider;
+        Self {
+            decider: Arc::new(move |e| match e {
+                ActorError::Panicked { .. } => Directive::Restart,
+                _ => decider(e),
+            }),
+            rejections: self.rejections,
+            factory: Some(factory),
+        }
+    }
+
+    pub(crate) fn decide(&self, error: &ActorError) -> Directive {
+        (self.decider)(error)
+    }
+
+    pub(crate) fn decide_rejected<M: Message>(&self, rejection: &<A as Handler<M>>::Rejection) -> Directive
+        where A: Handler<M>
+    {
+        self.rejections.get(&TypeId::of::<M>())
+            .and_then(|decider| decider(rejection))
+            .unwrap_or_else(|| self.decide(&ActorError::Rejected { message: type_name::<M>() }))
+    }
+
+    pub(crate) fn recreate(&self) -> Option<A> {
+        self.factory.as_ref().map(|factory| factory())
+    }
+
+    fn directive(directive: Directive) -> Self {
+        Self {
+            decider: Arc::new(move |_| directive),
+            rejections: HashMap::new(),
+            factory: None,
+        }
+    }
+}
+
+impl<A: Actor> Clone for SupervisorStrategy<A> {
+    fn clone(&self) -> Self {
+        Self {
+            decider: Arc::clone(&self.decider),
+            rejections: self.rejections.clone(),
+            factory: self.factory.clone(),
+        }
+    }
+}
+
+/// Resumes after a rejected message, but stops an Actor whose handler panicked,
+/// since its state may have been left half updated.
+impl<A: Actor> Default for SupervisorStrategy<A> {
+    fn default() -> Self {
+        Self::resume().decide_with(|e| match e {
+            ActorError::Panicked { .. } => Directive::Stop,
+            _ => Directive::Resume,
+        })
+    }
+}
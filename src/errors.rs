@@ -13,12 +13,45 @@ pub enum ActorError {
         id: ActorId
     },
 
+    #[error("The target actor: `{id}` did not stop within the deadline.")]
+    ShutdownTimeout {
+        id: ActorId
+    },
+
+    #[error("Mailbox of the target actor: `{id}` is full.")]
+    MailboxFull {
+        id: ActorId
+    },
+
+    #[error("The target actor: `{id}` did not reply within the deadline.")]
+    Timeout {
+        id: ActorId
+    },
+
     #[error("Could not execute callback, channel may be closed.")]
     CallBackSend,
 
+    #[error("Handler for message `{message}` returned a rejection.")]
+    Rejected {
+        message: &'static str
+    },
+
+    #[error("Handler for message `{message}` panicked: {reason}")]
+    Panicked {
+        message: &'static str,
+        reason: String
+    },
+
     #[error("May have passed different type information than what was expected when downcasting from `Any` to type.")]
     DownCastFromAny,
     
+    #[error("The actor `{id}` is a `{actual}`, not the expected `{expected}`.")]
+    TypeMismatch {
+        id: ActorId,
+        expected: &'static str,
+        actual: &'static str
+    },
+    
     #[error(transparent)]
     MissingExtension(ExtensionMissingError),
     
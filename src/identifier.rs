@@ -1,3 +1,7 @@
+mod path;
+
+pub use self::path::*;
+
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
@@ -0,0 +1,243 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::identifier::ActorId;
+
+/// Hierarchical name of an Actor such as `/user/orders/123`.
+///
+/// A path is identified by its textual form, so it can be used anywhere an identifier is expected
+/// and an Actor spawned as `"/user/orders/123"` is the same as one spawned with the parsed path.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ActorPath {
+    segments: Vec<Arc<str>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("`{path}` is not a valid actor path: {reason}")]
+pub struct InvalidActorPath {
+    pub path: String,
+    pub reason: &'static str,
+}
+
+impl ActorPath {
+    /// The path `/`, ancestor of every other path.
+    pub fn root() -> Self {
+        Self { segments: Vec::new() }
+    }
+
+    pub fn parse(path: &str) -> Result<Self, InvalidActorPath> {
+        let segments = split(path)?;
+        if segments.iter().any(|segment| segment.contains('*')) {
+            return Err(InvalidActorPath { path: path.to_string(), reason: "wildcards are only allowed in a selection" });
+        }
+
+        Ok(Self { segments: segments.into_iter().map(Arc::from).collect() })
+    }
+
+    /// Path of the child named `name`, which must not contain `/` or `*`.
+    pub fn child(&self, name: impl AsRef<str>) -> Result<Self, InvalidActorPath> {
+        let name = name.as_ref();
+        if name.is_empty() || name.contains(['/', '*']) {
+            return Err(InvalidActorPath { path: format!("{}/{}", self.trimmed(), name), reason: "child names must be a single segment" });
+        }
+
+        let mut segments = self.segments.clone();
+        segments.push(Arc::from(name));
+        Ok(Self { segments })
+    }
+
+    /// Path one level up, `None` for the root.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, parent) = self.segments.split_last()?;
+        Some(Self { segments: parent.to_vec() })
+    }
+
+    /// The last segment, `None` for the root.
+    pub fn name(&self) -> Option<&str> {
+        self.segments.last().map(AsRef::as_ref)
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().map(AsRef::as_ref)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Whether `self` is a strict ancestor of `other`.
+    pub fn is_ancestor_of(&self, other: &ActorPath) -> bool {
+        self.segments.len() < other.segments.len() && other.segments.starts_with(&self.segments)
+    }
+
+    fn trimmed(&self) -> String {
+        self.segments.iter().fold(String::new(), |path, segment| path + "/" + segment)
+    }
+}
+
+impl Display for ActorPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.segments.is_empty() {
+            return write!(f, "/");
+        }
+        write!(f, "{}", self.trimmed())
+    }
+}
+
+impl FromStr for ActorPath {
+    type Err = InvalidActorPath;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl ActorId {
+    /// The identifier read as an [`ActorPath`], `None` for identifiers that are not paths.
+    pub fn path(&self) -> Option<ActorPath> {
+        ActorPath::parse(&self.id).ok()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Segment {
+    Exact(Arc<str>),
+    /// `*`, any single segment.
+    Any,
+    /// `**`, one or more segments, only allowed last.
+    Rest,
+}
+
+/// Pattern over [`ActorPath`]s selecting a group of Actors, e.g. for a batch shutdown or a broadcast.
+///
+/// `*` matches exactly one segment and a trailing `**` matches one or more,
+/// so `/user/orders/*` selects the direct children of `/user/orders` and `/user/orders/**` all of its descendants.
+/// Identifiers that are not paths never match.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ActorSelection {
+    segments: Vec<Segment>,
+}
+
+impl ActorSelection {
+    pub fn parse(pattern: &str) -> Result<Self, InvalidActorPath> {
+        let raw = split(pattern)?;
+        let last = raw.len().saturating_sub(1);
+
+        let segments = raw.into_iter()
+            .enumerate()
+            .map(|(index, segment)| match segment {
+                "**" if index == last => Ok(Segment::Rest),
+                "**" => Err(InvalidActorPath { path: pattern.to_string(), reason: "`**` is only allowed as the last segment" }),
+                "*" => Ok(Segment::Any),
+                exact if exact.contains('*') => Err(InvalidActorPath { path: pattern.to_string(), reason: "wildcards must be a whole segment" }),
+                exact => Ok(Segment::Exact(Arc::from(exact))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { segments })
+    }
+
+    /// Every strict descendant of `path`.
+    pub fn descendants_of(path: &ActorPath) -> Self {
+        let mut segments = path.segments.iter()
+            .cloned()
+            .map(Segment::Exact)
+            .collect::<Vec<_>>();
+        segments.push(Segment::Rest);
+        Self { segments }
+    }
+
+    pub fn matches(&self, path: &ActorPath) -> bool {
+        let mut segments = path.segments.iter();
+        for pattern in &self.segments {
+            match pattern {
+                Segment::Rest => return segments.next().is_some(),
+                Segment::Any => if segments.next().is_none() {
+                    return false;
+                },
+                Segment::Exact(exact) => if segments.next() != Some(exact) {
+                    return false;
+                },
+            }
+        }
+        segments.next().is_none()
+    }
+
+    pub fn matches_id(&self, id: &ActorId) -> bool {
+        id.path().is_some_and(|path| self.matches(&path))
+    }
+}
+
+impl FromStr for ActorSelection {
+    type Err = InvalidActorPath;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn split(path: &str) -> Result<Vec<&str>, InvalidActorPath> {
+    let Some(relative) = path.strip_prefix('/') else {
+        return Err(InvalidActorPath { path: path.to_string(), reason: "must start with `/`" });
+    };
+
+    if relative.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let segments = relative.split('/').collect::<Vec<_>>();
+    if segments.iter().any(|segment| segment.is_empty()) {
+        return Err(InvalidActorPath { path: path.to_string(), reason: "segments must not be empty" });
+    }
+
+    Ok(segments)
+}
+
+
+#[cfg(test)]
+mod test {
+    use crate::identifier::{ActorPath, ActorSelection};
+    
+    #[test]
+    fn navigate() {
+        let path = ActorPath::parse("/user/orders/123").unwrap();
+        assert_eq!(path.to_string(), "/user/orders/123");
+        assert_eq!(path.name(), Some("123"));
+        assert_eq!(path.depth(), 3);
+        
+        let parent = path.parent().unwrap();
+        assert_eq!(parent.to_string(), "/user/orders");
+        assert!(parent.is_ancestor_of(&path));
+        assert!(!path.is_ancestor_of(&parent));
+        assert_eq!(parent.child("123").unwrap(), path);
+        
+        assert_eq!(ActorPath::root().to_string(), "/");
+        assert!(ActorPath::root().parent().is_none());
+        
+        assert!(ActorPath::parse("user/orders").is_err());
+        assert!(ActorPath::parse("/user//orders").is_err());
+        assert!(ActorPath::parse("/user/*").is_err());
+        assert!(parent.child("a/b").is_err());
+    }
+    
+    #[test]
+    fn select() {
+        let path = |path: &str| ActorPath::parse(path).unwrap();
+        
+        let children = ActorSelection::parse("/user/orders/*").unwrap();
+        assert!(children.matches(&path("/user/orders/123")));
+        assert!(!children.matches(&path("/user/orders")));
+        assert!(!children.matches(&path("/user/orders/123/items")));
+        
+        let descendants = ActorSelection::descendants_of(&path("/user/orders"));
+        assert_eq!(descendants, ActorSelection::parse("/user/orders/**").unwrap());
+        assert!(descendants.matches(&path("/user/orders/123")));
+        assert!(descendants.matches(&path("/user/orders/123/items/1")));
+        assert!(!descendants.matches(&path("/user/orders")));
+        assert!(!descendants.matches(&path("/user/carts/1")));
+        
+        assert!(ActorSelection::parse("/user/**/items").is_err());
+        assert!(ActorSelection::parse("/user/order*").is_err());
+    }
+}
@@ -7,3 +7,6 @@ pub mod identifier;
 
 #[cfg(feature = "persistence")]
 pub mod persistence;
+
+#[cfg(feature = "testkit")]
+pub mod testkit;
@@ -1,4 +1,6 @@
-use crate::actor::{Actor, FromContext};
+use std::time::Duration;
+
+use crate::actor::{Actor, FromContext, StopReason};
 use crate::errors::ActorError;
 use crate::persistence::context::PersistContext;
 use crate::persistence::errors::{PersistError, RecoveryError};
@@ -17,6 +19,12 @@ pub trait PersistenceActor: 'static + Sync + Send + Sized {
     #[allow(unused_variables)]
     async fn activate(&mut self, ctx: &mut PersistContext) -> Result<(), ActorError> { Ok(()) }
     
+    #[allow(unused_variables)]
+    async fn deactivate(&mut self, reason: StopReason, ctx: &mut PersistContext) -> Result<(), ActorError> { Ok(()) }
+    
+    /// See [`Actor::idle_timeout`].
+    fn idle_timeout() -> Option<Duration> { None }
+    
     async fn persist<E: Event>(&self, event: &E, ctx: &mut PersistContext) -> Result<(), PersistError> 
         where Self: RecoverJournal<E> + RecoveryMapping,
     {
@@ -77,5 +85,13 @@ impl<A: RecoveryMapping> Actor for A {
     async fn activate(&mut self, ctx: &mut PersistContext) -> Result<(), ActorError> {
         self.activate(ctx).await
     }
+    
+    async fn deactivate(&mut self, reason: StopReason, ctx: &mut PersistContext) -> Result<(), ActorError> {
+        PersistenceActor::deactivate(self, reason, ctx).await
+    }
+    
+    fn idle_timeout() -> Option<Duration> {
+        <A as PersistenceActor>::idle_timeout()
+    }
 }
 
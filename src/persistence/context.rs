@@ -1,4 +1,4 @@
-use crate::actor::{ActorContext, RunningState, State};
+use crate::actor::{ActorContext, RunningState, State, StopReason};
 use crate::identifier::{ActorId, IntoActorId};
 use crate::persistence::identifier::SequenceId;
 use crate::system::ActorSystem;
@@ -7,7 +7,7 @@ pub struct PersistContext {
     id: ActorId,
     system: ActorSystem,
     state: RunningState,
-    sequence: SequenceId
+    sequence: SequenceId,
 }
 
 impl PersistContext {
@@ -34,7 +34,7 @@ impl ActorContext for PersistContext {
     async fn shutdown(&self) {
         self.state.switch(|prev| async move {
             let mut write = prev.write().await;
-            *write = State::Shutdown;
+            *write = State::Shutdown(StopReason::Terminated);
         }).await;
     }
 
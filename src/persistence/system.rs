@@ -1,6 +1,6 @@
 use std::future::Future;
 
-use crate::actor::refs::ActorRef;
+use crate::actor::refs::{ActorRef, DynRef};
 use crate::actor::{Actor, ActorContext};
 use crate::errors::ActorError;
 use crate::identifier::{IntoActorId, ToActorId};
@@ -56,12 +56,16 @@ impl PersistSystemExt for ActorSystem {
         Fut: Future<Output = Option<A>> + 'static + Sync + Send
     {
         let actor_id = id.to_actor_id();
-        let refs = match self.registry.find(&actor_id).await {
-            Some((_, refs)) => refs.downcast::<A>()?,
-            None => {
+        let refs = match self.registry.find::<A>(&actor_id).await {
+            Ok(refs) if refs.is_active().await => refs,
+            _ => {
                 let persistence_id = id.to_actor_id().to_persistence_id();
                 let actor = or_nothing(id).await;
-                self.spawn_with_recovery(&persistence_id, actor).await?
+                match self.spawn_with_recovery(&persistence_id, actor).await {
+                    // another caller has spawned it in the meantime.
+                    Err(ActorError::AlreadySpawned { .. }) => self.registry.find::<A>(&actor_id).await?,
+                    spawned => spawned?,
+                }
             }
         };
         
@@ -1,43 +1,113 @@
+mod activity;
+mod config;
+mod deadletter;
+mod eventbus;
 mod extension;
+mod interceptor;
 mod lifecycle;
+mod metrics;
 mod registry;
+mod router;
+mod scheduler;
+mod shutdown;
 
-pub use self::extension::*;
+pub use self::{
+    config::*,
+    deadletter::*,
+    eventbus::*,
+    extension::*,
+    interceptor::*,
+    metrics::*,
+    router::*,
+    scheduler::*,
+    shutdown::*,
+};
+
+pub(crate) use self::activity::Activity;
 
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::actor::refs::ActorRef;
-use crate::actor::{Actor, ActorContext, FromMessage, Message, TryIntoActor};
+use crate::actor::refs::{ActorRef, DynRef, RegularAction};
+use crate::actor::{Actor, ActorContext, FromMessage, Handler, Message, StopReason, TryIntoActor};
 use crate::errors::ActorError;
-use crate::identifier::{IntoActorId, ToActorId};
+use crate::identifier::{ActorId, ActorSelection, IntoActorId, ToActorId};
+use crate::system::interceptor::Interceptors;
 use crate::system::registry::Registry;
 
 pub struct ActorSystem {
     pub(crate) ext: Arc<Extensions>,
-    pub(crate) registry: Registry
+    pub(crate) registry: Registry,
+    pub(crate) dead_letters: DeadLetters,
+    pub(crate) interceptors: Interceptors,
+    pub(crate) activity: Option<Activity>,
+    pub(crate) ask_timeout: Option<Duration>
 }
 
 #[async_trait::async_trait]
 pub trait LutetiumActorSystem: 'static + Sync + Send {
     async fn spawn<A: Actor>(&self, id: impl IntoActorId, actor: A) -> Result<ActorRef<A>, ActorError>;
+    async fn spawn_with<A: Actor>(&self, id: impl IntoActorId, actor: A, config: SpawnConfig<A>) -> Result<ActorRef<A>, ActorError>;
     async fn spawn_from<A: Actor, M: Message>(&self, from: M) -> Result<Result<ActorRef<A>, ActorError>, A::Rejection>
         where A: FromMessage<M>;
     async fn try_spawn<A: Actor, T: TryIntoActor<A>>(&self, id: T::Identifier, into: T) -> Result<Result<ActorRef<A>, ActorError>, T::Rejection>;
+    /// Shutdown the Actor and wait until its lifecycle has finished.
+    /// 
+    /// Actors of different types may share an identifier, all of them are shut down.
+    /// Use [`LutetiumActorSystem::shutdown_of`] to stop only one of them.
+    /// 
+    /// **note**: An Actor must not wait for its own shutdown, since its lifecycle is busy running the caller.
     async fn shutdown(&self, id: &impl ToActorId) -> Result<(), ActorError>;
+    /// Shutdown the Actor of type `A` registered under `id` and wait until its lifecycle has finished, 
+    /// leaving Actors of other types under the same identifier running.
+    /// 
+    /// Fails like [`LutetiumActorSystem::find`] if there is no such Actor.
+    async fn shutdown_of<A: Actor>(&self, id: &impl ToActorId) -> Result<(), ActorError>;
+    /// Same as [`LutetiumActorSystem::shutdown`], but gives up waiting with [`ActorError::ShutdownTimeout`] once `timeout` has elapsed.
+    async fn shutdown_timeout(&self, id: &impl ToActorId, timeout: Duration) -> Result<(), ActorError>;
+    /// Shutdown every Actor and wait until all lifecycles have finished.
     async fn shutdown_all(&self) -> Result<(), ActorError>;
+    /// Same as [`LutetiumActorSystem::shutdown_all`], but stops waiting once `timeout` has elapsed 
+    /// and reports the Actors that were still running.
+    async fn shutdown_all_timeout(&self, timeout: Duration) -> Result<ShutdownReport, ActorError>;
+    /// Find the Actor of type `A` registered under `id`.
+    /// 
+    /// Fails with [`ActorError::TypeMismatch`] naming both types if the identifier is only held by Actors of other types.
     async fn find<A: Actor>(&self, id: impl ToActorId) -> Result<ActorRef<A>, ActorError>;
     async fn find_or<A: Actor, I: ToActorId, Fn, Fut>(&self, id: I, or_nothing: Fn) -> Result<ActorRef<A>, ActorError> 
         where
             Fn: FnOnce(I) -> Fut + 'static + Sync + Send,
             Fut: Future<Output = A> + 'static + Sync + Send;
+    /// Identifiers of every tracked Actor, including those still shutting down.
+    /// 
+    /// An identifier shared by Actors of different types is listed once.
+    async fn ids(&self) -> Vec<ActorId>;
+    /// Number of tracked Actors, counting every type under a shared identifier.
+    async fn count(&self) -> usize;
+    async fn contains(&self, id: impl ToActorId) -> bool;
+    /// Every tracked Actor of type `A`.
+    async fn actors_of<A: Actor>(&self) -> Vec<ActorRef<A>>;
+    /// Every tracked Actor of type `A` whose path matches `selection`.
+    async fn select<A: Actor>(&self, selection: &ActorSelection) -> Vec<ActorRef<A>>;
+    /// Deliver a copy of `msg` with [`RegularAction::send`] to every Actor of type `A` whose path matches `selection`, 
+    /// returning how many of them accepted it into their mailbox.
+    async fn broadcast<A, M: Message + Clone>(&self, selection: &ActorSelection, msg: M) -> usize
+        where A: Actor + Handler<M>;
+    /// Shutdown every Actor whose path matches `selection` and wait until their lifecycles have finished.
+    async fn shutdown_selection(&self, selection: &ActorSelection) -> Result<ShutdownReport, ActorError>;
 }
 
 #[async_trait::async_trait]
 impl LutetiumActorSystem for ActorSystem {
     async fn spawn<A: Actor>(&self, id: impl IntoActorId, actor: A) -> Result<ActorRef<A>, ActorError> {
+        self.spawn_with(id, actor, SpawnConfig::default()).await
+    }
+    
+    async fn spawn_with<A: Actor>(&self, id: impl IntoActorId, actor: A, config: SpawnConfig<A>) -> Result<ActorRef<A>, ActorError> {
         let id = id.into_actor_id();
-        let behavior = Factory::create(actor, id.clone(), self.clone());
+        let behavior = Factory::create(actor, id.clone(), self.clone())
+            .configure(config);
         let registered = self.registry
             .register(id, behavior)
             .await?;
@@ -66,23 +136,38 @@ impl LutetiumActorSystem for ActorSystem {
     
     async fn shutdown(&self, id: &impl ToActorId) -> Result<(), ActorError> {
         self.registry
-            .deregister(&id.to_actor_id())
+            .deregister(&id.to_actor_id(), None)
             .await
     }
     
-    async fn shutdown_all(&self) -> Result<(), ActorError> {
+    async fn shutdown_of<A: Actor>(&self, id: &impl ToActorId) -> Result<(), ActorError> {
         self.registry
-            .shutdown_all()
+            .deregister_of::<A>(&id.to_actor_id(), None)
             .await
     }
     
+    async fn shutdown_timeout(&self, id: &impl ToActorId, timeout: Duration) -> Result<(), ActorError> {
+        self.registry
+            .deregister(&id.to_actor_id(), Some(timeout))
+            .await
+    }
+    
+    async fn shutdown_all(&self) -> Result<(), ActorError> {
+        self.registry
+            .shutdown_all(None)
+            .await;
+        Ok(())
+    }
+    
+    async fn shutdown_all_timeout(&self, timeout: Duration) -> Result<ShutdownReport, ActorError> {
+        let report = self.registry
+            .shutdown_all(Some(timeout))
+            .await;
+        Ok(report)
+    }
+    
     async fn find<A: Actor>(&self, id: impl ToActorId) -> Result<ActorRef<A>, ActorError> {
-        let id = id.to_actor_id();
-        let Some((_, actor)) = self.registry.find(&id).await else {
-            return Err(ActorError::NotFoundActor { id })
-        };
-        let refs = actor.downcast::<A>()?;
-        Ok(refs)
+        self.registry.find::<A>(&id.to_actor_id()).await
     }
     
     async fn find_or<A: Actor, I: ToActorId, Fn, Fut>(&self, id: I, or_nothing: Fn) -> Result<ActorRef<A>, ActorError> 
@@ -91,22 +176,70 @@ impl LutetiumActorSystem for ActorSystem {
             Fut: Future<Output = A> + 'static + Sync + Send
     {
         let i = id.to_actor_id();
-        match self.registry.find(&i).await {
-            Some((_, actor)) => {
-                actor.downcast::<A>()
-            },
-            None => {
+        match self.registry.find::<A>(&i).await {
+            Ok(refs) if refs.is_active().await => Ok(refs),
+            _ => {
                 let actor = or_nothing(id).await;
-                self.spawn(i, actor).await
+                match self.spawn(i.clone(), actor).await {
+                    // another caller has spawned it in the meantime.
+                    Err(ActorError::AlreadySpawned { .. }) => self.registry.find::<A>(&i).await,
+                    spawned => spawned,
+                }
             }
         }
     }
+    
+    async fn ids(&self) -> Vec<ActorId> {
+        self.registry.ids()
+    }
+    
+    async fn count(&self) -> usize {
+        self.registry.len()
+    }
+    
+    async fn contains(&self, id: impl ToActorId) -> bool {
+        self.registry.contains(&id.to_actor_id())
+    }
+    
+    async fn actors_of<A: Actor>(&self) -> Vec<ActorRef<A>> {
+        self.registry.actors_of::<A>()
+    }
+    
+    async fn select<A: Actor>(&self, selection: &ActorSelection) -> Vec<ActorRef<A>> {
+        self.registry.select::<A>(|id| selection.matches_id(id))
+    }
+    
+    async fn broadcast<A, M: Message + Clone>(&self, selection: &ActorSelection, msg: M) -> usize
+        where A: Actor + Handler<M>
+    {
+        self.select::<A>(selection).await
+            .iter()
+            .filter(|refs| match refs.send(msg.clone()) {
+                Ok(_) => true,
+                Err(e) => {
+                    tracing::warn!("broadcast could not be delivered to actor: {}: {}", refs.id(), e);
+                    false
+                }
+            })
+            .count()
+    }
+    
+    async fn shutdown_selection(&self, selection: &ActorSelection) -> Result<ShutdownReport, ActorError> {
+        let report = self.registry
+            .shutdown_where(|id| selection.matches_id(id), StopReason::Terminated, None)
+            .await;
+        Ok(report)
+    }
 }
 
 impl ActorSystem {
     pub fn builder() -> SystemBuilder {
         SystemBuilder {
             ext: Default::default(),
+            dead_letters: Default::default(),
+            interceptors: Default::default(),
+            activity: None,
+            ask_timeout: None,
         }
     }
 }
@@ -115,6 +248,10 @@ impl ActorSystem {
     pub fn extension(&self) -> &Arc<Extensions> {
         &self.ext
     }
+    
+    pub(crate) fn dead_letters(&self) -> &DeadLetters {
+        &self.dead_letters
+    }
 }
 
 impl Clone for ActorSystem {
@@ -122,6 +259,10 @@ impl Clone for ActorSystem {
         Self { 
             ext: Arc::clone(&self.ext),
             registry: self.registry.clone(),
+            dead_letters: self.dead_letters.clone(),
+            interceptors: self.interceptors.clone(),
+            activity: self.activity.clone(),
+            ask_timeout: self.ask_timeout,
         }
     }
 }
@@ -136,17 +277,27 @@ impl Factory {
 
 pub(crate) struct Behavior<A: Actor> {
     actor: A,
-    ctx: A::Context
+    ctx: A::Context,
+    config: SpawnConfig<A>
 }
 
 impl<A: Actor> Behavior<A> {
     pub fn new(actor: A, ctx: A::Context) -> Self {
-        Self { actor, ctx }
+        Self { actor, ctx, config: SpawnConfig::default() }
+    }
+    
+    pub fn configure(mut self, config: SpawnConfig<A>) -> Self {
+        self.config = config;
+        self
     }
 }
 
 pub struct SystemBuilder {
-    ext: Extensions
+    ext: Extensions,
+    dead_letters: DeadLetters,
+    interceptors: Interceptors,
+    activity: Option<Activity>,
+    ask_timeout: Option<Duration>
 }
 
 impl SystemBuilder {
@@ -155,10 +306,40 @@ impl SystemBuilder {
         self
     }
     
+    /// Replace the sink receiving messages whose failure nobody waits for.
+    pub fn dead_letters(&mut self, listener: impl DeadLetterListener) -> &mut Self {
+        self.dead_letters = DeadLetters::new(listener);
+        self
+    }
+    
+    /// Wrap every Actor of the system in `interceptor`, outside of the interceptors of the Actor itself.
+    pub fn interceptor(&mut self, interceptor: impl Interceptor) -> &mut Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+    
+    /// Default deadline for [`RegularAction::ask`](crate::actor::refs::RegularAction::ask) and 
+    /// [`RegularAction::tell`](crate::actor::refs::RegularAction::tell) on every Actor of the system.
+    pub fn ask_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.ask_timeout = Some(timeout);
+        self
+    }
+    
+    /// Track the work left in the system, so that the [`TestKit`](crate::testkit::TestKit) can wait until it is idle.
+    #[cfg(feature = "testkit")]
+    pub(crate) fn track_activity(&mut self) -> &mut Self {
+        self.activity = Some(Activity::default());
+        self
+    }
+    
     pub fn build(self) -> ActorSystem {
         ActorSystem {
             ext: Arc::new(self.ext),
             registry: Registry::default(),
+            dead_letters: self.dead_letters,
+            interceptors: self.interceptors,
+            activity: self.activity,
+            ask_timeout: self.ask_timeout,
         }
     }
 }
\ No newline at end of file
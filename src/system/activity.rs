@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::sync::Notify;
+
+/// Work left in a system: messages waiting in a mailbox or being handled, and lifecycles shutting down.
+///
+/// Only tracked for systems built by the `TestKit` of the `testkit` feature, which waits for it to drop to zero.
+#[derive(Clone, Default)]
+pub(crate) struct Activity(Arc<Inner>);
+
+#[derive(Default)]
+struct Inner {
+    pending: AtomicUsize,
+    idle: Notify,
+}
+
+impl Activity {
+    pub fn begin(&self) {
+        self.0.pending.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn end(&self, count: usize) {
+        if count == 0 {
+            return;
+        }
+        if self.0.pending.fetch_sub(count, Ordering::SeqCst) == count {
+            self.0.idle.notify_waiters();
+        }
+    }
+
+    /// Wait until no work is left, letting the tasks woken by the last piece of work run as well.
+    #[cfg(feature = "testkit")]
+    pub async fn idle(&self) {
+        loop {
+            let idle = self.0.idle.notified();
+            tokio::pin!(idle);
+            idle.as_mut().enable();
+
+            if self.0.pending.load(Ordering::SeqCst) == 0 {
+                tokio::task::yield_now().await;
+                if self.0.pending.load(Ordering::SeqCst) == 0 {
+                    return;
+                }
+                continue;
+            }
+
+            idle.await;
+        }
+    }
+}
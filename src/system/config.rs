@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use crate::actor::{Actor, SupervisorStrategy};
+use crate::actor::refs::{AnyRef, MailboxConfig};
+use crate::system::Interceptor;
+use crate::system::interceptor::Interceptors;
+
+/// Options applied to a single Actor when it is spawned.
+///
+/// Anything left unset falls back to the defaults declared on the [`Actor`] implementation.
+pub struct SpawnConfig<A: Actor> {
+    pub(crate) supervisor: Option<SupervisorStrategy<A>>,
+    pub(crate) mailbox: MailboxConfig,
+    pub(crate) idle_timeout: Option<Duration>,
+    pub(crate) parent: Option<AnyRef>,
+    pub(crate) interceptors: Interceptors,
+}
+
+impl<A: Actor> SpawnConfig<A> {
+    pub fn supervisor(mut self, strategy: SupervisorStrategy<A>) -> Self {
+        self.supervisor = Some(strategy);
+        self
+    }
+    
+    pub fn mailbox(mut self, mailbox: MailboxConfig) -> Self {
+        self.mailbox = mailbox;
+        self
+    }
+    
+    /// See [`Actor::idle_timeout`].
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+    
+    /// Wrap the handlers of the Actor in `interceptor`, see [`Interceptor`] for the order of several ones.
+    pub fn interceptor(mut self, interceptor: impl Interceptor) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+    
+    pub(crate) fn child_of(mut self, parent: AnyRef) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+}
+
+impl<A: Actor> Default for SpawnConfig<A> {
+    fn default() -> Self {
+        Self { 
+            supervisor: None, 
+            mailbox: MailboxConfig::default(), 
+            idle_timeout: None, 
+            parent: None, 
+            interceptors: Interceptors::default(),
+        }
+    }
+}
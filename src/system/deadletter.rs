@@ -0,0 +1,114 @@
+use std::any::Any;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::actor::Message;
+use crate::identifier::ActorId;
+
+/// A message that was never delivered to its handler, or whose failed result had nobody to receive it.
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    target: ActorId,
+    message: &'static str,
+    reason: DeadLetterReason,
+    timestamp: SystemTime,
+    payload: Option<Arc<dyn Any + Sync + Send>>,
+}
+
+impl DeadLetter {
+    pub(crate) fn new(target: ActorId, message: &'static str, reason: DeadLetterReason) -> DeadLetter {
+        Self { target, message, reason, timestamp: SystemTime::now(), payload: None }
+    }
+    
+    pub(crate) fn with_payload<M: Message>(mut self, payload: M) -> DeadLetter {
+        self.payload = Some(Arc::new(payload));
+        self
+    }
+    
+    /// Identifier of the Actor the message was sent to.
+    pub fn target(&self) -> &ActorId {
+        &self.target
+    }
+    
+    /// Type name of the message.
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+    
+    pub fn reason(&self) -> &DeadLetterReason {
+        &self.reason
+    }
+    
+    /// When the message became a dead letter.
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+    
+    /// The undelivered message, if it is of type `M`.
+    /// 
+    /// Only messages that never reached their handler are kept, 
+    /// a message that was handled has been consumed by it.
+    pub fn payload<M: Message>(&self) -> Option<&M> {
+        self.payload.as_deref()?.downcast_ref()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DeadLetterReason {
+    /// The handler returned a rejection.
+    Rejected,
+    /// The handler panicked with the given reason.
+    Panicked(String),
+    /// The target had already stopped and its mailbox was closed.
+    MailboxClosed,
+    /// The bounded mailbox of the target was full, 
+    /// either refusing the message or dropping it according to its [`OverflowPolicy`](crate::actor::refs::OverflowPolicy).
+    MailboxFull,
+    /// The message was still waiting in the mailbox when the target stopped.
+    Unprocessed,
+    /// The caller stopped waiting before the message was handled, so it was skipped.
+    Abandoned,
+    /// An [`Interceptor`](crate::system::Interceptor) refused the message.
+    Intercepted,
+}
+
+/// Sink receiving every [`DeadLetter`] of an [`ActorSystem`](crate::system::ActorSystem).
+/// 
+/// Installed with [`SystemBuilder::dead_letters`](crate::system::SystemBuilder::dead_letters), 
+/// otherwise [`LogDeadLetters`] is used.
+pub trait DeadLetterListener: 'static + Sync + Send {
+    fn receive(&self, letter: DeadLetter);
+}
+
+/// Writes every [`DeadLetter`] to the log.
+pub struct LogDeadLetters;
+
+impl DeadLetterListener for LogDeadLetters {
+    fn receive(&self, letter: DeadLetter) {
+        tracing::warn!("dead letter: `{}` to actor: {}, reason: {:?}", letter.message, letter.target, letter.reason);
+    }
+}
+
+pub(crate) struct DeadLetters(Arc<dyn DeadLetterListener>);
+
+impl DeadLetters {
+    pub fn new(listener: impl DeadLetterListener) -> DeadLetters {
+        Self(Arc::new(listener))
+    }
+    
+    pub fn publish(&self, letter: DeadLetter) {
+        self.0.receive(letter)
+    }
+}
+
+impl Clone for DeadLetters {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Default for DeadLetters {
+    fn default() -> Self {
+        Self::new(LogDeadLetters)
+    }
+}
@@ -0,0 +1,134 @@
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::actor::{Actor, Handler, Message};
+use crate::actor::refs::{ActorCell, ActorRef, RegularAction};
+use crate::errors::ActorError;
+
+type Deliver<M> = Arc<dyn Fn(M) -> Result<(), ActorError> + Sync + Send>;
+
+#[derive(Default)]
+struct Topics {
+    events: HashMap<TypeId, Box<dyn Topic>>,
+    /// Subscribers whose clean-up has been spawned, by the address of their cell 
+    /// which stays the same as long as the clean-up holds the cell.
+    monitored: HashSet<usize>,
+}
+
+trait Topic: 'static + Sync + Send {
+    fn remove(&mut self, cell: &ActorCell);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+/// Subscribers are told apart by their cell, since Actors of different types may share an identifier.
+struct Subscribers<M>(Vec<(ActorCell, Deliver<M>)>);
+
+impl<M: Message> Topic for Subscribers<M> {
+    fn remove(&mut self, cell: &ActorCell) {
+        if self.0.iter().any(|(subscribed, _)| subscribed.is(cell)) {
+            tracing::trace!("actor: {} unsubscribed from `{}`.", cell.0.id, std::any::type_name::<M>());
+        }
+        self.0.retain(|(subscribed, _)| !subscribed.is(cell));
+    }
+    
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Publish/subscribe fan-out of events to Actors, where the type of the event is the topic.
+/// 
+/// Install it through [`SystemBuilder::extension`](crate::system::SystemBuilder::extension) 
+/// and extract it with [`Extension<EventBus>`](crate::actor::Extension).
+/// Events are delivered with [`RegularAction::send`], so a failing handler ends up in the dead letters.
+/// A subscriber is dropped from every topic once its Actor has stopped.
+#[derive(Clone, Default)]
+pub struct EventBus {
+    topics: Arc<RwLock<Topics>>
+}
+
+impl EventBus {
+    /// Subscribe the Actor to events of type `M`, replacing its previous subscription if any.
+    pub fn subscribe<A, M: Message + Clone>(&self, subscriber: &ActorRef<A>)
+        where A: Actor + Handler<M>
+    {
+        let cell = subscriber.cell.clone();
+        let refs = subscriber.clone();
+        let deliver: Deliver<M> = Arc::new(move |event| refs.send(event));
+        
+        let mut topics = self.write();
+        let subscribers = topics.events.entry(TypeId::of::<M>())
+            .or_insert_with(|| Box::new(Subscribers::<M>(Vec::new())))
+            .as_any_mut()
+            .downcast_mut::<Subscribers<M>>()
+            .expect("topics are keyed by the type of their event");
+        subscribers.0.retain(|(subscribed, _)| !subscribed.is(&cell));
+        subscribers.0.push((cell.clone(), deliver));
+        
+        if !topics.monitored.insert(Arc::as_ptr(&cell.0) as usize) {
+            return;
+        }
+        
+        let bus = self.clone();
+        tokio::spawn(async move {
+            cell.terminated().await;
+            
+            let mut topics = bus.write();
+            for topic in topics.events.values_mut() {
+                topic.remove(&cell);
+            }
+            topics.monitored.remove(&(Arc::as_ptr(&cell.0) as usize));
+        });
+    }
+    
+    pub fn unsubscribe<A: Actor, M: Message>(&self, subscriber: &ActorRef<A>) {
+        self.remove::<M>(&subscriber.cell);
+    }
+    
+    fn remove<M: Message>(&self, cell: &ActorCell) {
+        if let Some(subscribers) = self.write().events.get_mut(&TypeId::of::<M>()) {
+            subscribers.remove(cell);
+        }
+    }
+    
+    /// Deliver a copy of `event` to every subscriber of `M`, returning how many of them accepted it into their mailbox.
+    pub fn publish<M: Message + Clone>(&self, event: M) -> usize {
+        let topics = self.read();
+        let Some(subscribers) = topics.events.get(&TypeId::of::<M>())
+            .and_then(|subscribers| subscribers.as_any().downcast_ref::<Subscribers<M>>()) else {
+            return 0;
+        };
+        
+        subscribers.0.iter()
+            .filter(|(cell, deliver)| match deliver(event.clone()) {
+                Ok(_) => true,
+                Err(e) => {
+                    tracing::warn!("event could not be delivered to actor: {}: {}", cell.0.id, e);
+                    false
+                }
+            })
+            .count()
+    }
+    
+    pub fn subscribers<M: Message>(&self) -> usize {
+        self.read()
+            .events
+            .get(&TypeId::of::<M>())
+            .and_then(|subscribers| subscribers.as_any().downcast_ref::<Subscribers<M>>())
+            .map_or(0, |subscribers| subscribers.0.len())
+    }
+    
+    fn read(&self) -> RwLockReadGuard<'_, Topics> {
+        self.topics.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+    
+    fn write(&self) -> RwLockWriteGuard<'_, Topics> {
+        self.topics.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -0,0 +1,92 @@
+use std::any::type_name;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::actor::{Actor, ActorContext, SupervisorStrategy};
+use crate::actor::refs::{ActorCell, Applier, Failure};
+use crate::errors::ActorError;
+use crate::identifier::ActorId;
+use crate::system::DeadLetterReason;
+
+/// A single handling of a message, as seen by an [`Interceptor`].
+#[derive(Debug, Clone)]
+pub struct Invocation {
+    pub actor_id: ActorId,
+    /// Name of the Actor type, as given by [`std::any::type_name`].
+    pub actor_type: &'static str,
+    /// Name of the message type, as given by [`std::any::type_name`].
+    pub message: &'static str,
+}
+
+/// Layer wrapped around every [`Handler::call`](crate::actor::Handler::call) of an Actor, e.g. for auditing or rate limiting.
+///
+/// Installed for the whole system with [`SystemBuilder::interceptor`](crate::system::SystemBuilder::interceptor)
+/// or for a single Actor with [`SpawnConfig::interceptor`](crate::system::SpawnConfig::interceptor).
+/// Layers run in the order they were added, system layers first, and [`Interceptor::after`] runs in the reverse order.
+/// Control messages of the system, such as a shutdown or the stop signal of a supervisor, are not intercepted.
+#[async_trait::async_trait]
+pub trait Interceptor: 'static + Sync + Send {
+    /// Runs before the handler.
+    ///
+    /// Returning an error refuses the message: the handler, the remaining layers and every [`Interceptor::after`] are skipped,
+    /// the caller receives the error and the message goes to the dead letters as [`DeadLetterReason::Intercepted`].
+    /// A refused message is not a failure of the Actor, so its supervisor is not involved.
+    #[allow(unused_variables)]
+    async fn before(&self, invocation: &Invocation) -> Result<(), ActorError> { Ok(()) }
+
+    /// Runs after the handler with the time it took and its result,
+    /// where an `Err` is the failure handed to the supervisor.
+    #[allow(unused_variables)]
+    async fn after(&self, invocation: &Invocation, elapsed: Duration, result: &Result<(), ActorError>) {}
+}
+
+/// Stack of [`Interceptor`]s applied to an Actor.
+#[derive(Clone, Default)]
+pub(crate) struct Interceptors(Vec<Arc<dyn Interceptor>>);
+
+impl Interceptors {
+    pub fn push(&mut self, interceptor: impl Interceptor) {
+        self.0.push(Arc::new(interceptor));
+    }
+
+    /// `self` wrapped around `inner`.
+    pub fn around(&self, inner: Interceptors) -> Interceptors {
+        Self(self.0.iter().cloned().chain(inner.0).collect())
+    }
+
+    pub async fn apply<A: Actor>(
+        &self, 
+        payload: Box<dyn Applier<A>>, 
+        actor: &mut A, 
+        ctx: &mut A::Context, 
+        supervisor: &SupervisorStrategy<A>, 
+        cell: &ActorCell
+    ) -> Result<(), Failure> {
+        let Some(message) = payload.message().filter(|_| !self.0.is_empty()) else {
+            return payload.apply(actor, ctx, supervisor).await;
+        };
+
+        let invocation = Invocation { actor_id: ctx.id().clone(), actor_type: type_name::<A>(), message };
+
+        for interceptor in &self.0 {
+            if let Err(e) = interceptor.before(&invocation).await {
+                tracing::debug!("`{}` was refused by an interceptor: {}", message, e);
+                cell.undeliverable(payload, e, DeadLetterReason::Intercepted);
+                return Ok(());
+            }
+        }
+
+        let started = Instant::now();
+        let (res, directive) = match payload.apply(actor, ctx, supervisor).await {
+            Ok(()) => (Ok(()), None),
+            Err(Failure { error, directive }) => (Err(error), directive),
+        };
+        let elapsed = started.elapsed();
+
+        for interceptor in self.0.iter().rev() {
+            interceptor.after(&invocation, elapsed, &res).await;
+        }
+
+        res.map_err(|error| Failure { error, directive })
+    }
+}
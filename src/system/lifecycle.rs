@@ -1,24 +1,64 @@
 use std::sync::Arc;
+use std::time::Instant;
 use tracing::Instrument;
-use crate::actor::{Actor, ActorContext};
-use crate::actor::refs::{ActorCell, ActorRef, Applier, InnerCell};
+use crate::actor::{Actor, ActorContext, Directive, StopReason};
+use crate::actor::refs::{self, ActorCell, ActorRef, Applier, CatchUnwind, Failure, InnerCell};
 use crate::errors::ActorError;
-use crate::system::Behavior;
+use crate::system::{Behavior, DeadLetterReason, Metrics};
 use crate::system::registry::Registry;
 
 pub(crate) struct LifeCycle;
 
 impl LifeCycle {
     pub async fn spawn<A: Actor>(registry: Registry, behavior: Behavior<A>) -> Result<ActorRef<A>, ActorError> {
-        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Box<dyn Applier<A>>>();
-        let Behavior { mut actor, mut ctx } = behavior;
+        let Behavior { mut actor, mut ctx, config } = behavior;
+        let (tx, mut rx) = refs::channel::<Box<dyn Applier<A>>>(config.mailbox);
+        let supervisor = config.supervisor.unwrap_or_else(A::supervisor);
+        let idle_timeout = config.idle_timeout.or_else(A::idle_timeout);
+        let (terminated, rx_terminated) = tokio::sync::watch::channel(false);
+        let metrics = ctx.system().extension().get::<Metrics>().map(Metrics::of::<A>);
+        let interceptors = ctx.system().interceptors.around(config.interceptors);
         let cell = ActorCell(Arc::new(InnerCell {
-            running_state: ctx.state().clone()
+            id: ctx.id().clone(),
+            running_state: ctx.state().clone(),
+            terminated: rx_terminated,
+            ask_timeout: ctx.system().ask_timeout,
+            parent: config.parent,
+            children: Default::default(),
+            watchers: Default::default(),
+            watching: Default::default(),
+            metrics,
+            dead_letters: ctx.system().dead_letters().clone(),
+            activity: ctx.system().activity.clone(),
         }));
         
-        let refs = ActorRef::new(cell, tx);
+        let refs = ActorRef::new(cell.clone(), tx);
+        
+        ctx.state().bind(refs.clone().into());
+        
+        registry.reserve(ctx.id(), refs.clone().into())?;
 
-        actor.activate(&mut ctx).await?;
+        if let Err(e) = actor.activate(&mut ctx).await {
+            let _ = registry.untracked(ctx.id(), &cell).await;
+            ctx.state().unbind();
+            let remains = rx.close();
+            if let Some(metrics) = cell.metrics() {
+                metrics.dequeued(remains.len());
+            }
+            cell.end_work(remains.len());
+            for payload in remains {
+                cell.undeliverable(payload, ActorError::CallBackSend, DeadLetterReason::Unprocessed);
+            }
+            return Err(e);
+        }
+        
+        if let Some(parent) = cell.parent() {
+            parent.cell().adopt(refs.clone().into());
+        }
+        
+        if let Some(metrics) = cell.metrics() {
+            metrics.activated();
+        }
 
         let span = ctx.id().to_owned();
         
@@ -30,9 +70,125 @@ impl LifeCycle {
 
             tracing::trace!("resource moved to tokio thread lifecycle");
             
-            while let Some(payload) = rx.recv().await {
-                if let Err(e) = payload.apply(&mut actor, &mut ctx).await {
-                    tracing::error!("{}", e)
+            // Whether a message taken out of the mailbox is still counted as work in progress.
+            let mut handling = false;
+            
+            // Whether the current instance has already been deactivated, by a restart that panicked halfway.
+            let mut deactivated = false;
+            
+            loop {
+                if handling {
+                    cell.end_work(1);
+                    handling = false;
+                }
+                
+                let received = match idle_timeout {
+                    Some(idle) => match tokio::time::timeout(idle, rx.recv()).await {
+                        Ok(received) => received,
+                        Err(_) => {
+                            tracing::debug!("actor has been idle for {:?}, passivate.", idle);
+                            ctx.state().stop(StopReason::Passivated).await;
+                            break;
+                        }
+                    },
+                    None => rx.recv().await,
+                };
+                
+                let Some(payload) = received else {
+                    break;
+                };
+                
+                handling = true;
+                
+                if let Some(metrics) = cell.metrics() {
+                    metrics.dequeued(1);
+                }
+                
+                if payload.is_cancelled() {
+                    tracing::debug!("caller has given up waiting, skip message.");
+                    cell.undeliverable(payload, ActorError::CallBackSend, DeadLetterReason::Abandoned);
+                    continue;
+                }
+                
+                // Control messages of the system are not handled by the Actor, so they stay out of its metrics.
+                let handled = payload.message().is_some();
+                let started = Instant::now();
+                let res = interceptors.apply(payload, &mut actor, &mut ctx, &supervisor, &cell).await;
+                
+                if let Some(metrics) = cell.metrics().filter(|_| handled) {
+                    let failed = matches!(res, Err(ref failure) if !matches!(failure.error, ActorError::CallBackSend));
+                    metrics.processed(started.elapsed(), failed);
+                }
+                
+                match res {
+                    Ok(_) => {}
+                    Err(Failure { error: ActorError::CallBackSend, .. }) => {
+                        tracing::warn!("{}", ActorError::CallBackSend);
+                    }
+                    Err(Failure { error: e, directive }) => match directive.unwrap_or_else(|| supervisor.decide(&e)) {
+                        Directive::Resume => {
+                            tracing::error!("{}", e);
+                        }
+                        Directive::Restart => {
+                            tracing::error!("{}, restart actor.", e);
+                            let Some(fresh) = supervisor.recreate() else {
+                                tracing::error!("supervisor has no factory to restart the actor, stop instead.");
+                                ctx.state().stop(StopReason::Supervisor).await;
+                                break;
+                            };
+
+                            match CatchUnwind(actor.deactivate(StopReason::Restarted, &mut ctx)).await {
+                                Ok(Ok(())) => {}
+                                Ok(Err(e)) => tracing::error!("{}", e),
+                                Err(reason) => {
+                                    tracing::error!("actor panicked while deactivating for a restart: {}, stop instead.", reason);
+                                    ctx.state().stop(StopReason::Supervisor).await;
+                                    deactivated = true;
+                                    break;
+                                }
+                            }
+
+                            actor = fresh;
+                            
+                            if let Some(metrics) = cell.metrics() {
+                                metrics.restarted();
+                            }
+
+                            match CatchUnwind(actor.activate(&mut ctx)).await {
+                                Ok(Ok(())) => {}
+                                Ok(Err(e)) => {
+                                    tracing::error!("restarted actor failed to activate: {}", e);
+                                    ctx.state().stop(StopReason::Supervisor).await;
+                                    break;
+                                }
+                                Err(reason) => {
+                                    tracing::error!("restarted actor panicked while activating: {}", reason);
+                                    ctx.state().stop(StopReason::Supervisor).await;
+                                    break;
+                                }
+                            }
+                        }
+                        Directive::Stop => {
+                            tracing::error!("{}, stop actor.", e);
+                            ctx.state().stop(StopReason::Supervisor).await;
+                            break;
+                        }
+                        Directive::Escalate => {
+                            match cell.parent() {
+                                Some(parent) => {
+                                    tracing::error!("{}, escalate to parent actor: {}.", e, parent.id());
+                                    if let Err(e) = parent.escalate(ctx.id().clone(), e) {
+                                        tracing::error!("failed to escalate to parent actor: {}", e);
+                                    }
+                                }
+                                None => {
+                                    tracing::error!("{}, actor has no parent to escalate to, stop instead.", e);
+                                }
+                            }
+                            ctx.state().stop(StopReason::Supervisor).await;
+                            break;
+                        }
+                    }
                 }
 
                 if ctx.state().available_shutdown().await {
@@ -41,12 +197,64 @@ impl LifeCycle {
                 }
             }
             
-            tracing::trace!("actor was shutdown.");
+            // The shutdown is work in progress as well, carried over from the message that caused it if any.
+            if !handling {
+                cell.begin_work();
+            }
+            
+            ctx.state().stop(StopReason::ChannelClosed).await;
+            let reason = ctx.state().reason().await.unwrap_or(StopReason::ChannelClosed);
             
-            if let Err(e) = registry.untracked(ctx.id()).await {
+            tracing::trace!("actor was shutdown. reason: {:?}", reason);
+            
+            // Nothing reads the mailbox from here on, close it so that callers still waiting, 
+            // such as children asking their parent while stopping, fail instead of waiting forever.
+            let remains = rx.close();
+            if let Some(metrics) = cell.metrics() {
+                metrics.dequeued(remains.len());
+            }
+            cell.end_work(remains.len());
+            for payload in remains {
+                cell.undeliverable(payload, ActorError::CallBackSend, DeadLetterReason::Unprocessed);
+            }
+            
+            let children = cell.children();
+            for child in &children {
+                if let Err(e) = child.terminate(StopReason::Parent).await {
+                    tracing::trace!("child actor: {} is already stopping: {}", child.id(), e);
+                }
+            }
+            for child in &children {
+                child.terminated().await;
+            }
+            
+            if !deactivated {
+                match CatchUnwind(actor.deactivate(reason, &mut ctx)).await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(e)) => tracing::error!("{}", e),
+                    Err(panic) => tracing::error!("actor panicked while deactivating: {}", panic),
+                }
+            }
+            
+            if let Err(e) = registry.untracked(ctx.id(), &cell).await {
                 tracing::error!("{}", e);
             }
             
+            if let Some(parent) = cell.parent() {
+                parent.cell().abandon(&cell);
+            }
+            
+            cell.notify_watchers(reason);
+            cell.unwatch_all();
+            ctx.state().unbind();
+            
+            if let Some(metrics) = cell.metrics() {
+                metrics.stopped();
+            }
+            
+            let _ = terminated.send(true);
+            cell.end_work(1);
+            
             tracing::trace!("lifecycle ended.");
         }.instrument(tracing::trace_span!("{}", actor_id = %span)));
 
@@ -0,0 +1,231 @@
+use std::any::{type_name, TypeId};
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::time::Duration;
+
+use dashmap::DashMap;
+
+use crate::actor::Actor;
+
+/// Upper bounds in seconds of the handler latency buckets, the defaults of the Prometheus client libraries.
+pub const LATENCY_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Runtime metrics of every Actor type, collected by the lifecycle and the [`ActorRef`](crate::actor::refs::ActorRef)s.
+///
+/// Install it through [`SystemBuilder::extension`](crate::system::SystemBuilder::extension)
+/// and read it back with [`Metrics::snapshot`] or render it with a [`MetricsExporter`].
+/// Only Actors spawned by a system with the extension installed are measured.
+#[derive(Clone, Default)]
+pub struct Metrics {
+    types: Arc<DashMap<TypeId, Arc<ActorMetrics>>>
+}
+
+impl Metrics {
+    pub(crate) fn of<A: Actor>(&self) -> Arc<ActorMetrics> {
+        self.types.entry(TypeId::of::<A>())
+            .or_insert_with(|| Arc::new(ActorMetrics::new(type_name::<A>())))
+            .clone()
+    }
+
+    /// Current value of every metric, one entry per Actor type ordered by type name.
+    pub fn snapshot(&self) -> Vec<ActorMetricsSnapshot> {
+        let mut snapshot = self.types.iter()
+            .map(|metrics| metrics.snapshot())
+            .collect::<Vec<_>>();
+        snapshot.sort_by(|a, b| a.actor_type.cmp(b.actor_type));
+        snapshot
+    }
+
+    pub fn export<E: MetricsExporter>(&self, exporter: &E) -> E::Output {
+        exporter.export(&self.snapshot())
+    }
+
+    /// Shorthand for exporting with [`PrometheusExporter`].
+    pub fn render_prometheus(&self) -> String {
+        self.export(&PrometheusExporter)
+    }
+}
+
+/// Counters of a single Actor type.
+pub(crate) struct ActorMetrics {
+    actor_type: &'static str,
+    live: AtomicI64,
+    mailbox_depth: AtomicI64,
+    processed: AtomicU64,
+    errors: AtomicU64,
+    restarts: AtomicU64,
+    latency: Histogram,
+}
+
+impl ActorMetrics {
+    fn new(actor_type: &'static str) -> Self {
+        Self {
+            actor_type,
+            live: AtomicI64::new(0),
+            mailbox_depth: AtomicI64::new(0),
+            processed: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            restarts: AtomicU64::new(0),
+            latency: Histogram::default(),
+        }
+    }
+
+    pub(crate) fn activated(&self) {
+        self.live.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn stopped(&self) {
+        self.live.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn enqueued(&self) {
+        self.mailbox_depth.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn dequeued(&self, count: usize) {
+        self.mailbox_depth.fetch_sub(count as i64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn processed(&self, elapsed: Duration, failed: bool) {
+        self.processed.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.latency.observe(elapsed);
+    }
+
+    pub(crate) fn restarted(&self) {
+        self.restarts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> ActorMetricsSnapshot {
+        ActorMetricsSnapshot {
+            actor_type: self.actor_type,
+            live: self.live.load(Ordering::Relaxed).max(0) as u64,
+            mailbox_depth: self.mailbox_depth.load(Ordering::Relaxed).max(0) as u64,
+            processed: self.processed.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            restarts: self.restarts.load(Ordering::Relaxed),
+            latency: self.latency.snapshot(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Histogram {
+    buckets: [AtomicU64; LATENCY_BUCKETS.len()],
+    count: AtomicU64,
+    sum_nanos: AtomicU64,
+}
+
+impl Histogram {
+    fn observe(&self, elapsed: Duration) {
+        let seconds = elapsed.as_secs_f64();
+        if let Some(bucket) = LATENCY_BUCKETS.iter().position(|bound| seconds <= *bound) {
+            self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        }
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_nanos.fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> HistogramSnapshot {
+        let mut cumulative = 0;
+        let buckets = LATENCY_BUCKETS.iter()
+            .zip(&self.buckets)
+            .map(|(bound, count)| {
+                cumulative += count.load(Ordering::Relaxed);
+                (*bound, cumulative)
+            })
+            .collect();
+
+        HistogramSnapshot {
+            buckets,
+            count: self.count.load(Ordering::Relaxed),
+            sum: Duration::from_nanos(self.sum_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// Metrics of one Actor type at the time of [`Metrics::snapshot`].
+#[derive(Debug, Clone)]
+pub struct ActorMetricsSnapshot {
+    /// Name of the Actor type, as given by [`std::any::type_name`].
+    pub actor_type: &'static str,
+    /// Actors that have activated and not yet stopped.
+    pub live: u64,
+    /// Messages waiting in the mailboxes of every live Actor.
+    pub mailbox_depth: u64,
+    pub processed: u64,
+    /// Messages whose handling failed and was handed to the supervisor.
+    pub errors: u64,
+    pub restarts: u64,
+    pub latency: HistogramSnapshot,
+}
+
+/// Handler latencies, with cumulative counts as in Prometheus.
+#[derive(Debug, Clone)]
+pub struct HistogramSnapshot {
+    /// Upper bound in seconds and number of observations less than or equal to it, see [`LATENCY_BUCKETS`].
+    pub buckets: Vec<(f64, u64)>,
+    pub count: u64,
+    pub sum: Duration,
+}
+
+/// Turns a [`Metrics::snapshot`] into the format of a monitoring system.
+pub trait MetricsExporter {
+    type Output;
+    fn export(&self, metrics: &[ActorMetricsSnapshot]) -> Self::Output;
+}
+
+/// Renders the metrics in the Prometheus text exposition format, ready to be served from a `/metrics` endpoint.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrometheusExporter;
+
+impl MetricsExporter for PrometheusExporter {
+    type Output = String;
+
+    fn export(&self, metrics: &[ActorMetricsSnapshot]) -> String {
+        let mut out = String::new();
+
+        let series: [Series; 5] = [
+            ("lutetium_actors_live", "gauge", "Actors that are currently running.", |m| m.live),
+            ("lutetium_mailbox_depth", "gauge", "Messages waiting in the mailboxes.", |m| m.mailbox_depth),
+            ("lutetium_messages_processed_total", "counter", "Messages handled by the actors.", |m| m.processed),
+            ("lutetium_handler_errors_total", "counter", "Messages whose handling failed.", |m| m.errors),
+            ("lutetium_actor_restarts_total", "counter", "Actors restarted by their supervisor.", |m| m.restarts),
+        ];
+
+        for (name, kind, help, value) in series {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} {kind}");
+            for m in metrics {
+                let _ = writeln!(out, "{name}{{actor_type=\"{}\"}} {}", escape(m.actor_type), value(m));
+            }
+        }
+
+        let name = "lutetium_handler_duration_seconds";
+        let _ = writeln!(out, "# HELP {name} Time spent handling a message.");
+        let _ = writeln!(out, "# TYPE {name} histogram");
+        for m in metrics {
+            let actor_type = escape(m.actor_type);
+            for (bound, count) in &m.latency.buckets {
+                let _ = writeln!(out, "{name}_bucket{{actor_type=\"{actor_type}\",le=\"{bound}\"}} {count}");
+            }
+            let _ = writeln!(out, "{name}_bucket{{actor_type=\"{actor_type}\",le=\"+Inf\"}} {}", m.latency.count);
+            let _ = writeln!(out, "{name}_sum{{actor_type=\"{actor_type}\"}} {}", m.latency.sum.as_secs_f64());
+            let _ = writeln!(out, "{name}_count{{actor_type=\"{actor_type}\"}} {}", m.latency.count);
+        }
+
+        out
+    }
+}
+
+/// Name, Prometheus type, help text and value of a single-valued metric.
+type Series = (&'static str, &'static str, &'static str, fn(&ActorMetricsSnapshot) -> u64);
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
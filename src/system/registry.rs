@@ -1,37 +1,57 @@
-use std::collections::HashMap;
+use std::any::{type_name, TypeId};
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 
-use tokio::sync::RwLock;
+use dashmap::DashMap;
+use tokio::task::JoinSet;
 
-use crate::actor::Actor;
-use crate::actor::refs::{ActorRef, AnyRef, DynRef};
+use crate::actor::{Actor, StopReason};
+use crate::actor::refs::{ActorCell, ActorRef, AnyRef, DynRef};
 use crate::errors::ActorError;
 use crate::identifier::ActorId;
-use crate::system::Behavior;
+use crate::system::{Behavior, ShutdownReport};
 use crate::system::lifecycle::LifeCycle;
 
-pub(crate) struct Registry(Arc<RwLock<HashMap<ActorId, AnyRef>>>);
+/// Every tracked Actor keyed by its identifier, sharded so that lookups do not contend on a single lock.
+/// 
+/// Identifiers are namespaced by Actor type, 
+/// so Actors of different types can be spawned under the same identifier without colliding.
+/// Each identifier therefore holds at most one Actor per type.
+pub(crate) struct Registry(Arc<DashMap<ActorId, Vec<AnyRef>>>);
 
 impl Registry {
+    /// Spawn the Actor and track it, see [`Registry::reserve`] for how the identifier is claimed.
     pub async fn register<A: Actor>(&self, id: ActorId, behavior: Behavior<A>) -> Result<ActorRef<A>, ActorError> {
-        if let Some((_, actor)) = self.find(&id).await { 
-            if actor.is_active().await {
-                return Err(ActorError::AlreadySpawned { id })
-            }
-        }
-        
         let refs = LifeCycle::spawn(self.clone(), behavior).await?;
-
-        if self.0.write().await
-            .insert(id.clone(), AnyRef::from(refs.clone())).is_some()
-        {
-            tracing::warn!("Actor during shutdown in the registry has been overwritten.");
-        }
         
         tracing::info!("Registered actor: {}", id);
         
         Ok(refs)
     }
+    
+    /// Claim the identifier for an Actor about to be activated, 
+    /// failing with [`ActorError::AlreadySpawned`] if an active Actor of the same type holds it.
+    /// 
+    /// The identifier is claimed atomically, so only one of several concurrent spawns for it succeeds. 
+    /// An Actor that is still shutting down is overwritten.
+    pub fn reserve(&self, id: &ActorId, refs: AnyRef) -> Result<(), ActorError> {
+        let mut tracked = self.0.entry(id.clone()).or_default();
+        match tracked.iter().position(|actor| actor.actor_type() == refs.actor_type()) {
+            Some(index) if tracked[index].cell().0.running_state.is_active_now() => {
+                Err(ActorError::AlreadySpawned { id: id.clone() })
+            }
+            Some(index) => {
+                tracing::warn!("Actor during shutdown in the registry has been overwritten.");
+                tracked[index] = refs;
+                Ok(())
+            }
+            None => {
+                tracked.push(refs);
+                Ok(())
+            }
+        }
+    }
 
     /// Deregister Actor from the [`Registry`].
     /// 
@@ -44,13 +64,44 @@ impl Registry {
     /// 2. Lifecycle checks shutdown flag
     /// 3. Channel is broken
     /// 4. Registry is told to remove itself from tracking
-    /// 5. Complete
-    pub async fn deregister(&self, id: &ActorId) -> Result<(), ActorError> {
-        let Some((id, actor)) = self.find(id).await else {
+    /// 5. Lifecycle notifies that it has finished
+    /// 6. Complete
+    /// 
+    /// If `timeout` elapses before step 5, [`ActorError::ShutdownTimeout`] is returned 
+    /// while the Actor keeps shutting down in the background.
+    /// 
+    /// Every Actor registered under `id` is shut down, whatever its type.
+    pub async fn deregister(&self, id: &ActorId, timeout: Option<Duration>) -> Result<(), ActorError> {
+        let actors = self.find_any(id);
+        if actors.is_empty() {
             return Err(ActorError::NotFoundActor { id: id.clone() })
+        }
+        
+        self.stop(id, actors, timeout).await
+    }
+    
+    /// Same as [`Registry::deregister`], but only for the Actor of type `A` registered under `id`.
+    pub async fn deregister_of<A: Actor>(&self, id: &ActorId, timeout: Option<Duration>) -> Result<(), ActorError> {
+        let actor = self.find::<A>(id).await?;
+        self.stop(id, vec![AnyRef::from(actor)], timeout).await
+    }
+    
+    async fn stop(&self, id: &ActorId, actors: Vec<AnyRef>, timeout: Option<Duration>) -> Result<(), ActorError> {
+        let shutdown = async {
+            for actor in &actors {
+                actor.shutdown().await?;
+            }
+            for actor in &actors {
+                actor.terminated().await;
+            }
+            Ok(())
         };
         
-        actor.shutdown().await?;
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, shutdown).await
+                .map_err(|_| ActorError::ShutdownTimeout { id: id.clone() })??,
+            None => shutdown.await?
+        }
         
         tracing::warn!("De-Registered actor: {}", id);
         Ok(())
@@ -58,49 +109,154 @@ impl Registry {
     
     #[allow(unused)]
     pub async fn track<A: Actor>(&self, id: ActorId, refs: ActorRef<A>) -> Result<(), ActorError> {
-        if (self.find(&id).await).is_some() {
-            return Err(ActorError::AlreadySpawned { id })
+        let mut tracked = self.0.entry(id.clone()).or_default();
+        if tracked.iter().any(AnyRef::is::<A>) {
+            return Err(ActorError::AlreadySpawned { id });
         }
-        
-        self.0.write().await
-            .insert(id.clone(), AnyRef::from(refs.clone()));
-        
+        tracked.push(AnyRef::from(refs));
         Ok(())
     }
     
-    /// Remove the `ActorRef` indicated by Identifier from the current registry tracking.
+    /// Remove the `ActorRef` of the Actor owning `cell` from the current registry tracking.
     /// 
-    /// This will cause the ActorRef to no longer be maintained, 
-    /// so the Actor will stop as soon as all known ActorRefs of the target are dropped.
-    pub async fn untracked(&self, id: &ActorId) -> Result<(), ActorError> {
-        let Some((id, _)) = self.find(id).await else {
-            return Err(ActorError::NotFoundActor { id: id.clone() })
+    /// Called by the [`lifecycle`](crate::system::lifecycle) once the Actor has stopped or failed to activate, 
+    /// removing it does not stop the Actor by itself.
+    /// 
+    /// Nothing is removed if another Actor has since been registered under the same identifier, 
+    /// e.g. an entity re-created by `find_or` while the passivated one was still stopping.
+    pub async fn untracked(&self, id: &ActorId, cell: &ActorCell) -> Result<(), ActorError> {
+        let removed = {
+            let Some(mut tracked) = self.0.get_mut(id) else {
+                return Err(ActorError::NotFoundActor { id: id.clone() })
+            };
+            let before = tracked.len();
+            tracked.retain(|actor| !actor.cell().is(cell));
+            tracked.len() < before
         };
+        self.0.remove_if(id, |_, tracked| tracked.is_empty());
         
-        let mut lock = self.0.write().await;
-        if lock.remove(&id).is_none() {
-            return Err(ActorError::NotFoundActor { id: id.to_owned() })
+        if !removed {
+            tracing::debug!("actor: {} has already been replaced in the registry.", id);
+            return Ok(())
         }
         
         tracing::warn!("untracked actor: {}", id);
         Ok(())
     }
 
-    pub async fn find(&self, id: &ActorId) -> Option<(ActorId, AnyRef)> {
-        self.0.read().await
-            .iter()
-            .find(|(dest, _)| dest.eq(&id))
-            .map(|(i, a)| (i.clone(), a.clone()))
+    /// Find the Actor of type `A` registered under `id`.
+    /// 
+    /// Fails with [`ActorError::TypeMismatch`] if only Actors of other types hold the identifier.
+    pub async fn find<A: Actor>(&self, id: &ActorId) -> Result<ActorRef<A>, ActorError> {
+        let Some(tracked) = self.0.get(id) else {
+            return Err(ActorError::NotFoundActor { id: id.clone() })
+        };
+        
+        if let Some(actor) = tracked.iter().find(|actor| actor.is::<A>()) {
+            return actor.clone().downcast::<A>();
+        }
+        
+        match tracked.first() {
+            Some(other) => Err(ActorError::TypeMismatch { id: id.clone(), expected: type_name::<A>(), actual: other.type_name() }),
+            None => Err(ActorError::NotFoundActor { id: id.clone() })
+        }
+    }
+    
+    /// Every Actor registered under `id`, whatever its type.
+    pub fn find_any(&self, id: &ActorId) -> Vec<AnyRef> {
+        self.0.get(id)
+            .map(|tracked| tracked.value().clone())
+            .unwrap_or_default()
+    }
+
+    pub fn ids(&self) -> Vec<ActorId> {
+        self.0.iter()
+            .map(|tracked| tracked.key().clone())
+            .collect()
+    }
+    
+    pub fn len(&self) -> usize {
+        self.0.iter()
+            .map(|tracked| tracked.len())
+            .sum()
+    }
+    
+    pub fn contains(&self, id: &ActorId) -> bool {
+        self.0.get(id).is_some_and(|tracked| !tracked.is_empty())
+    }
+    
+    pub fn actors_of<A: Actor>(&self) -> Vec<ActorRef<A>> {
+        self.select(|_| true)
+    }
+    
+    pub fn select<A: Actor>(&self, filter: impl Fn(&ActorId) -> bool) -> Vec<ActorRef<A>> {
+        self.0.iter()
+            .filter(|tracked| filter(tracked.key()))
+            .flat_map(|tracked| tracked.value().clone())
+            .filter_map(|actor| actor.downcast::<A>().ok())
+            .collect()
     }
 
-    pub async fn shutdown_all(&self) -> Result<(), ActorError> {
-        let lock = self.0.read().await;
-        for (id, actor) in lock.iter() {
-            if let Err(e) = actor.shutdown().await {
-                tracing::error!("{}: {}", id, e);
+    /// Stop every tracked Actor concurrently and wait for their lifecycles to finish.
+    pub async fn shutdown_all(&self, timeout: Option<Duration>) -> ShutdownReport {
+        self.shutdown_where(|_| true, StopReason::SystemShutdown, timeout).await
+    }
+    
+    /// Stop every tracked Actor whose identifier satisfies `filter` concurrently and wait for their lifecycles to finish.
+    /// 
+    /// The Actors are collected up front, 
+    /// so lifecycles can untrack themselves while the others are still stopping.
+    pub async fn shutdown_where(&self, filter: impl Fn(&ActorId) -> bool, reason: StopReason, timeout: Option<Duration>) -> ShutdownReport {
+        let actors = self.0.iter()
+            .filter(|tracked| filter(tracked.key()))
+            .flat_map(|tracked| tracked.value().clone())
+            .collect::<Vec<_>>();
+        
+        // Actors of different types may share an identifier, so they are told apart by their type as well.
+        let pending = actors.iter()
+            .map(|actor| (actor.actor_type(), actor.id().clone()))
+            .collect::<Vec<_>>();
+        
+        let mut tasks = JoinSet::new();
+        for actor in actors {
+            tasks.spawn(async move {
+                if let Err(e) = actor.terminate(reason).await {
+                    tracing::error!("{}: {}", actor.id(), e);
+                }
+                actor.terminated().await;
+                (actor.actor_type(), actor.id().clone())
+            });
+        }
+        
+        let mut stopped: Vec<(TypeId, ActorId)> = Vec::with_capacity(pending.len());
+        let drain = async {
+            while let Some(joined) = tasks.join_next().await {
+                match joined {
+                    Ok(id) => stopped.push(id),
+                    Err(e) => tracing::error!("{}", e),
+                }
             }
+        };
+        
+        match timeout {
+            Some(timeout) => {
+                if tokio::time::timeout(timeout, drain).await.is_err() {
+                    tracing::warn!("some actors did not stop within {:?}.", timeout);
+                }
+            }
+            None => drain.await
+        }
+        
+        let finished = stopped.iter().collect::<HashSet<_>>();
+        let timed_out = pending.into_iter()
+            .filter(|actor| !finished.contains(actor))
+            .map(|(_, id)| id)
+            .collect();
+        
+        ShutdownReport { 
+            stopped: stopped.into_iter().map(|(_, id)| id).collect(), 
+            timed_out,
         }
-        Ok(())
     }
 }
 
@@ -112,6 +268,6 @@ impl Clone for Registry {
 
 impl Default for Registry {
     fn default() -> Self {
-        Self(Arc::new(RwLock::new(HashMap::new())))
+        Self(Arc::new(DashMap::new()))
     }
 }
\ No newline at end of file
@@ -0,0 +1,358 @@
+use std::collections::hash_map::{DefaultHasher, RandomState};
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::sync::Mutex;
+use tokio::task::JoinSet;
+
+use crate::actor::{Actor, Handler, Message, StopReason};
+use crate::actor::refs::{ActorRef, RegularAction};
+use crate::errors::ActorError;
+use crate::identifier::{ActorId, IntoActorId};
+use crate::system::{ActorSystem, LutetiumActorSystem, SpawnConfig};
+
+/// How a [`Router`] picks the members of its pool that handle a message.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Routing {
+    /// Each member in turn.
+    RoundRobin,
+    /// A member picked at random.
+    Random,
+    /// The member with the fewest messages waiting in its mailbox.
+    SmallestMailbox,
+    /// The same member for the same key given through [`Router::key`],
+    /// moving as few keys as possible when the pool is resized.
+    ///
+    /// Messages sent without a key are routed round-robin.
+    ConsistentHash,
+    /// Every member.
+    Broadcast,
+}
+
+/// Handle to a pool of identical Actors spawned through [`ActorSystem::spawn_pool`].
+///
+/// Members are registered as `{router id}/{n}` and are restarted when they panic,
+/// any other failure is left to [`Actor::supervisor`].
+/// A member stopped by its supervisor is replaced by a fresh one,
+/// any other member that stops leaves the pool.
+///
+/// Messages must be [`Clone`] since [`Routing::Broadcast`] delivers a copy to every member.
+pub struct Router<A: Actor>(Arc<Pool<A>>);
+
+struct Pool<A: Actor> {
+    id: ActorId,
+    system: ActorSystem,
+    routing: Routing,
+    factory: Arc<dyn Fn() -> A + Sync + Send>,
+    members: RwLock<Vec<ActorRef<A>>>,
+    resizing: Mutex<()>,
+    cursor: AtomicUsize,
+    sequence: AtomicUsize,
+}
+
+enum Targets<A: Actor> {
+    One(ActorRef<A>),
+    All(Vec<ActorRef<A>>),
+}
+
+impl ActorSystem {
+    /// Spawn `size` Actors created by `factory` behind a single [`Router`].
+    pub async fn spawn_pool<A: Actor, F>(&self, id: impl IntoActorId, size: usize, routing: Routing, factory: F) -> Result<Router<A>, ActorError>
+        where F: Fn() -> A + 'static + Sync + Send
+    {
+        let router = Router(Arc::new(Pool {
+            id: id.into_actor_id(),
+            system: self.clone(),
+            routing,
+            factory: Arc::new(factory),
+            members: RwLock::new(Vec::new()),
+            resizing: Mutex::new(()),
+            cursor: AtomicUsize::new(0),
+            sequence: AtomicUsize::new(0),
+        }));
+
+        router.resize(size).await?;
+
+        Ok(router)
+    }
+}
+
+impl<A: Actor> Router<A> {
+    pub fn id(&self) -> &ActorId {
+        &self.0.id
+    }
+
+    pub fn routing(&self) -> Routing {
+        self.0.routing
+    }
+
+    pub fn members(&self) -> Vec<ActorRef<A>> {
+        self.0.read().clone()
+    }
+
+    pub fn size(&self) -> usize {
+        self.0.read().len()
+    }
+
+    /// Spawn or stop members until the pool holds `size` of them.
+    pub async fn resize(&self, size: usize) -> Result<(), ActorError> {
+        let _resizing = self.0.resizing.lock().await;
+
+        while self.size() < size {
+            let member = self.0.spawn_member().await?;
+            self.0.write().push(member);
+        }
+
+        let surplus = {
+            let mut members = self.0.write();
+            let keep = size.min(members.len());
+            members.split_off(keep)
+        };
+
+        for member in surplus {
+            if let Err(e) = self.0.system.shutdown_of::<A>(member.id()).await {
+                tracing::warn!("pool member: {} could not be stopped: {}", member.id(), e);
+            }
+        }
+
+        tracing::debug!("pool: {} resized to {} members.", self.0.id, size);
+
+        Ok(())
+    }
+
+    /// Route by `key` under [`Routing::ConsistentHash`], the key is ignored by the other strategies.
+    pub fn key<K: Hash>(&self, key: &K) -> Keyed<'_, A> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        Keyed { router: self, hash: hasher.finish() }
+    }
+
+    /// See [`RegularAction::ask`].
+    ///
+    /// Under [`Routing::Broadcast`] every member is asked and the first reply is returned.
+    pub async fn ask<M: Message + Clone>(&self, msg: M) -> Result<Result<A::Accept, A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        self.0.ask(None, msg).await
+    }
+
+    /// See [`RegularAction::tell`].
+    ///
+    /// Under [`Routing::Broadcast`] every member is told and the first failure is returned.
+    pub async fn tell<M: Message + Clone>(&self, msg: M) -> Result<Result<(), A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        self.0.tell(None, msg).await
+    }
+
+    /// See [`RegularAction::send`].
+    pub fn send<M: Message + Clone>(&self, msg: M) -> Result<(), ActorError>
+        where A: Handler<M>
+    {
+        self.0.send(None, msg)
+    }
+
+    /// Stop every member of the pool and wait for them.
+    pub async fn shutdown(&self) -> Result<(), ActorError> {
+        self.resize(0).await
+    }
+}
+
+impl<A: Actor> Clone for Router<A> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+/// A [`Router`] routing by key, created by [`Router::key`].
+pub struct Keyed<'a, A: Actor> {
+    router: &'a Router<A>,
+    hash: u64,
+}
+
+impl<A: Actor> Keyed<'_, A> {
+    pub async fn ask<M: Message + Clone>(&self, msg: M) -> Result<Result<A::Accept, A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        self.router.0.ask(Some(self.hash), msg).await
+    }
+
+    pub async fn tell<M: Message + Clone>(&self, msg: M) -> Result<Result<(), A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        self.router.0.tell(Some(self.hash), msg).await
+    }
+
+    pub fn send<M: Message + Clone>(&self, msg: M) -> Result<(), ActorError>
+        where A: Handler<M>
+    {
+        self.router.0.send(Some(self.hash), msg)
+    }
+}
+
+impl<A: Actor> Pool<A> {
+    fn select(&self, key: Option<u64>) -> Result<Targets<A>, ActorError> {
+        let members = self.read();
+        if members.is_empty() {
+            return Err(ActorError::NotFoundActor { id: self.id.clone() });
+        }
+
+        let round_robin = || self.cursor.fetch_add(1, Ordering::Relaxed) % members.len();
+
+        let picked = match (self.routing, key) {
+            (Routing::Broadcast, _) => return Ok(Targets::All(members.clone())),
+            (Routing::RoundRobin, _) | (Routing::ConsistentHash, None) => &members[round_robin()],
+            (Routing::Random, _) => {
+                let random = RandomState::new().hash_one(self.cursor.fetch_add(1, Ordering::Relaxed));
+                &members[(random % members.len() as u64) as usize]
+            }
+            (Routing::SmallestMailbox, _) => members.iter()
+                .min_by_key(|member| member.mailbox_len())
+                .expect("pool is not empty"),
+            // Rendezvous hashing, the member scoring highest for the key wins.
+            (Routing::ConsistentHash, Some(key)) => members.iter()
+                .max_by_key(|member| {
+                    let mut hasher = DefaultHasher::new();
+                    key.hash(&mut hasher);
+                    member.id().hash(&mut hasher);
+                    hasher.finish()
+                })
+                .expect("pool is not empty"),
+        };
+
+        Ok(Targets::One(picked.clone()))
+    }
+
+    async fn ask<M: Message + Clone>(&self, key: Option<u64>, msg: M) -> Result<Result<A::Accept, A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        match self.select(key)? {
+            Targets::One(member) => member.ask(msg).await,
+            Targets::All(members) => {
+                let mut asks = JoinSet::new();
+                for member in members {
+                    let msg = msg.clone();
+                    asks.spawn(async move { member.ask(msg).await });
+                }
+
+                let first = asks.join_next().await
+                    .expect("pool is not empty")
+                    .map_err(|_| ActorError::CallBackSend)?;
+                asks.detach_all();
+                first
+            }
+        }
+    }
+
+    async fn tell<M: Message + Clone>(&self, key: Option<u64>, msg: M) -> Result<Result<(), A::Rejection>, ActorError>
+        where A: Handler<M>
+    {
+        match self.select(key)? {
+            Targets::One(member) => member.tell(msg).await,
+            Targets::All(members) => {
+                let mut tells = JoinSet::new();
+                for member in members {
+                    let msg = msg.clone();
+                    tells.spawn(async move { member.tell(msg).await });
+                }
+
+                let mut settled = Ok(Ok(()));
+                while let Some(told) = tells.join_next().await {
+                    let told = told.map_err(|_| ActorError::CallBackSend).and_then(|told| told);
+                    if matches!(settled, Ok(Ok(_))) && !matches!(told, Ok(Ok(_))) {
+                        settled = told;
+                    }
+                }
+                settled
+            }
+        }
+    }
+
+    fn send<M: Message + Clone>(&self, key: Option<u64>, msg: M) -> Result<(), ActorError>
+        where A: Handler<M>
+    {
+        match self.select(key)? {
+            Targets::One(member) => member.send(msg),
+            Targets::All(members) => {
+                let mut settled = Ok(());
+                for member in members {
+                    let sent = member.send(msg.clone());
+                    if settled.is_ok() {
+                        settled = sent;
+                    }
+                }
+                settled
+            }
+        }
+    }
+
+    async fn spawn_member(self: &Arc<Self>) -> Result<ActorRef<A>, ActorError> {
+        let id = format!("{}/{}", self.id, self.sequence.fetch_add(1, Ordering::Relaxed));
+        let supervisor = A::supervisor().restart_on_panic(Arc::clone(&self.factory));
+
+        let member = self.system
+            .spawn_with(id, (self.factory)(), SpawnConfig::default().supervisor(supervisor))
+            .await?;
+
+        Self::monitor(Arc::downgrade(self), member.clone());
+
+        Ok(member)
+    }
+
+    /// Replace `member` once it has been stopped by its supervisor, or drop it from the pool otherwise.
+    fn monitor(pool: Weak<Self>, member: ActorRef<A>) {
+        tokio::spawn(async move {
+            member.cell.terminated().await;
+
+            let Some(pool) = pool.upgrade() else {
+                return;
+            };
+
+            let reason = member.cell.0.running_state.reason().await;
+            pool.replace(member, reason).await;
+        });
+    }
+
+    async fn replace(self: Arc<Self>, member: ActorRef<A>, reason: Option<StopReason>) {
+        let _resizing = self.resizing.lock().await;
+
+        if !self.read().iter().any(|remains| remains.id().eq(member.id())) {
+            return;
+        }
+
+        let fresh = match reason {
+            Some(StopReason::Supervisor) => {
+                tracing::warn!("pool member: {} has failed, replace it.", member.id());
+                match self.spawn_member().await {
+                    Ok(fresh) => Some(fresh),
+                    Err(e) => {
+                        tracing::error!("failed to replace pool member: {}: {}", member.id(), e);
+                        None
+                    }
+                }
+            }
+            _ => None
+        };
+
+        let mut members = self.write();
+        let Some(index) = members.iter().position(|remains| remains.id().eq(member.id())) else {
+            return;
+        };
+
+        match fresh {
+            Some(fresh) => members[index] = fresh,
+            None => {
+                members.remove(index);
+            }
+        }
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Vec<ActorRef<A>>> {
+        self.members.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Vec<ActorRef<A>>> {
+        self.members.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
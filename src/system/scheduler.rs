@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::actor::{Actor, Handler, Message};
+use crate::actor::refs::{ActorRef, RegularAction};
+use crate::errors::ActorError;
+use crate::system::ActorSystem;
+
+/// Shortest period accepted by [`ActorSystem::schedule_interval`].
+const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Handle to a message scheduled for later delivery.
+/// 
+/// Dropping the handle does not cancel the timer, 
+/// it keeps running until it fires, is cancelled or the target Actor stops.
+pub struct TimerHandle(JoinHandle<()>);
+
+impl TimerHandle {
+    pub fn cancel(&self) {
+        self.0.abort();
+    }
+    
+    /// Whether the timer has fired for the last time, been cancelled or seen its target stop.
+    pub fn is_finished(&self) -> bool {
+        self.0.is_finished()
+    }
+}
+
+impl ActorSystem {
+    /// Deliver `msg` to `target` once `delay` has elapsed, unless the target has stopped by then.
+    pub fn schedule_once<A, M: Message>(&self, target: &ActorRef<A>, delay: Duration, msg: M) -> TimerHandle
+        where A: Actor + Handler<M>
+    {
+        let target = target.clone();
+        TimerHandle(tokio::spawn(async move {
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {
+                    if let Err(e) = target.send(msg) {
+                        tracing::warn!("scheduled message could not be delivered: {}", e);
+                    }
+                }
+                _ = target.cell.terminated() => {
+                    tracing::trace!("target actor: {} has stopped, timer cancelled.", target.id());
+                }
+            }
+        }))
+    }
+    
+    /// Deliver a copy of `msg` to `target` every `interval`, starting after the first `interval`, 
+    /// until the handle is cancelled or the target stops.
+    /// 
+    /// An `interval` shorter than 1ms, including zero, is raised to 1ms.
+    pub fn schedule_interval<A, M: Message + Clone>(&self, target: &ActorRef<A>, interval: Duration, msg: M) -> TimerHandle
+        where A: Actor + Handler<M>
+    {
+        let interval = interval.max(MIN_INTERVAL);
+        let target = target.clone();
+        TimerHandle(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval_at(Instant::now() + interval, interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            
+            let terminated = target.cell.terminated();
+            tokio::pin!(terminated);
+            
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {
+                        match target.send(msg.clone()) {
+                            Ok(_) => {}
+                            Err(ActorError::CallBackSend) => break,
+                            Err(e) => tracing::warn!("scheduled message could not be delivered: {}", e),
+                        }
+                    }
+                    _ = &mut terminated => {
+                        tracing::trace!("target actor: {} has stopped, timer cancelled.", target.id());
+                        break;
+                    }
+                }
+            }
+        }))
+    }
+}
@@ -0,0 +1,24 @@
+use crate::identifier::ActorId;
+
+/// Result of shutting down several Actors with a deadline.
+#[derive(Debug, Default)]
+pub struct ShutdownReport {
+    pub(crate) stopped: Vec<ActorId>,
+    pub(crate) timed_out: Vec<ActorId>,
+}
+
+impl ShutdownReport {
+    /// Actors whose lifecycle finished before the deadline.
+    pub fn stopped(&self) -> &[ActorId] {
+        &self.stopped
+    }
+    
+    /// Actors still running when the deadline passed.
+    pub fn timed_out(&self) -> &[ActorId] {
+        &self.timed_out
+    }
+    
+    pub fn is_complete(&self) -> bool {
+        self.timed_out.is_empty()
+    }
+}
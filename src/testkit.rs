@@ -0,0 +1,233 @@
+//! Helpers for testing Actors, enabled with the `testkit` feature.
+//!
+//! ```ignore
+//! let kit = TestKit::new();
+//! let mut probe = kit.probe::<OrderPlaced>();
+//! let orders = kit.spawn(Orders::new(probe.recipient())).await;
+//!
+//! kit.ask(&orders, PlaceOrder { id: 1 }).await?;
+//! assert_eq!(probe.expect_msg().await, OrderPlaced { id: 1 });
+//! probe.expect_no_msg(Duration::from_millis(50)).await;
+//! ```
+//!
+//! Run on a current-thread runtime with paused time, e.g. `#[tokio::test(start_paused = true)]` or [`deterministic_runtime`],
+//! every lifecycle runs on the thread of the test and [`TestKit::run_until_idle`] settles the system in the same order on every run.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+
+use crate::actor::{Actor, Handler, Message, StopReason};
+use crate::actor::refs::{ActorRef, Deliver, Recipient, RegularAction};
+use crate::errors::ActorError;
+use crate::identifier::ActorId;
+use crate::system::{ActorSystem, LutetiumActorSystem, SpawnConfig, SystemBuilder};
+
+/// How long expectations wait unless told otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(3);
+
+static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+fn unique(kind: &str) -> ActorId {
+    ActorId::new(format!("/testkit/{}-{}", kind, SEQUENCE.fetch_add(1, Ordering::Relaxed)))
+}
+
+/// Current-thread runtime with paused time, on which timers fire in order as soon as every task is waiting.
+pub fn deterministic_runtime() -> Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .build()
+        .expect("failed to build a current-thread runtime")
+}
+
+/// A throwaway [`ActorSystem`] with assertions on the Actors spawned into it.
+///
+/// Every helper panics instead of returning an error, so that a test fails at the expectation that was not met.
+pub struct TestKit {
+    system: ActorSystem,
+    timeout: Duration,
+}
+
+impl TestKit {
+    pub fn new() -> Self {
+        Self::with_builder(ActorSystem::builder())
+    }
+
+    /// Build the system from a builder prepared by the test, e.g. with extensions installed.
+    pub fn with_builder(mut builder: SystemBuilder) -> Self {
+        builder.track_activity();
+        Self { system: builder.build(), timeout: DEFAULT_TIMEOUT }
+    }
+
+    /// Replace [`DEFAULT_TIMEOUT`] for the expectations of this kit and of its probes.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn system(&self) -> &ActorSystem {
+        &self.system
+    }
+
+    /// Spawn the Actor under a unique identifier.
+    pub async fn spawn<A: Actor>(&self, actor: A) -> ActorRef<A> {
+        self.spawn_with(actor, SpawnConfig::default()).await
+    }
+
+    pub async fn spawn_with<A: Actor>(&self, actor: A, config: SpawnConfig<A>) -> ActorRef<A> {
+        let id = unique("actor");
+        self.system.spawn_with(id.clone(), actor, config).await
+            .unwrap_or_else(|e| panic!("actor: {} failed to spawn: {}", id, e))
+    }
+
+    pub fn probe<M: Message>(&self) -> TestProbe<M> {
+        TestProbe::new().timeout(self.timeout)
+    }
+
+    /// Ask the Actor and return the reply of its handler,
+    /// failing if the message could not be delivered or no reply arrived within the timeout.
+    pub async fn ask<A, M: Message>(&self, refs: &ActorRef<A>, msg: M) -> Result<A::Accept, A::Rejection>
+        where A: Actor + Handler<M>
+    {
+        refs.ask_timeout(msg, self.timeout).await
+            .unwrap_or_else(|e| panic!("actor: {} did not reply: {}", refs.id(), e))
+    }
+
+    /// Wait until the Actor has completely stopped and return why, failing if it is still running after the timeout.
+    pub async fn expect_stop<A: Actor>(&self, refs: &ActorRef<A>) -> StopReason {
+        if tokio::time::timeout(self.timeout, refs.cell.terminated()).await.is_err() {
+            panic!("actor: {} did not stop within {:?}", refs.id(), self.timeout);
+        }
+
+        refs.cell.0.running_state.reason().await
+            .unwrap_or_else(|| panic!("actor: {} went away without reporting its stop", refs.id()))
+    }
+
+    /// Wait until every mailbox is empty, no handler is running and no Actor is shutting down.
+    ///
+    /// Messages sent from handlers are processed as well, while timers that have not fired yet are not waited for.
+    /// Under paused time, a handler waiting for a timer lets the clock jump ahead to it.
+    pub async fn run_until_idle(&self) {
+        self.system.activity
+            .as_ref()
+            .expect("systems of a TestKit track their activity")
+            .idle()
+            .await
+    }
+
+    /// Shutdown every Actor of the system, failing if any of them is still running after the timeout.
+    pub async fn shutdown(self) {
+        let report = self.system.shutdown_all_timeout(self.timeout).await
+            .unwrap_or_else(|e| panic!("system did not shutdown: {}", e));
+
+        if !report.is_complete() {
+            let running = report.timed_out().iter().map(ToString::to_string).collect::<Vec<_>>();
+            panic!("actors did not stop within {:?}: {}", self.timeout, running.join(", "));
+        }
+    }
+}
+
+impl Default for TestKit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Stand-in for an Actor handling `M`, recording every message it receives.
+///
+/// Hand [`TestProbe::recipient`] to the Actor under test in place of the real collaborator.
+/// `ask` and `tell` through the recipient are answered with `Ok(())` as soon as the message is recorded.
+pub struct TestProbe<M: Message> {
+    id: ActorId,
+    sender: mpsc::UnboundedSender<M>,
+    received: mpsc::UnboundedReceiver<M>,
+    timeout: Duration,
+}
+
+impl<M: Message> TestProbe<M> {
+    pub fn new() -> Self {
+        let (sender, received) = mpsc::unbounded_channel();
+        Self { id: unique("probe"), sender, received, timeout: DEFAULT_TIMEOUT }
+    }
+
+    /// Replace [`DEFAULT_TIMEOUT`] for the expectations of this probe.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn id(&self) -> &ActorId {
+        &self.id
+    }
+
+    pub fn recipient(&self) -> Recipient<M> {
+        Recipient::new(Probe { id: self.id.clone(), sender: self.sender.clone() })
+    }
+
+    /// Next message received, failing if none arrives within the timeout.
+    pub async fn expect_msg(&mut self) -> M {
+        self.expect_msg_within(self.timeout).await
+    }
+
+    pub async fn expect_msg_within(&mut self, timeout: Duration) -> M {
+        match tokio::time::timeout(timeout, self.received.recv()).await {
+            Ok(Some(msg)) => msg,
+            Ok(None) => unreachable!("the probe holds a sender itself"),
+            Err(_) => panic!("probe: {} received no message within {:?}", self.id, timeout),
+        }
+    }
+
+    /// Fail if any message arrives within `duration`.
+    pub async fn expect_no_msg(&mut self, duration: Duration) {
+        if let Ok(Some(_)) = tokio::time::timeout(duration, self.received.recv()).await {
+            panic!("probe: {} received an unexpected `{}`", self.id, std::any::type_name::<M>());
+        }
+    }
+
+    /// The next `n` messages in the order they were received, failing if they do not all arrive within the timeout.
+    pub async fn receive_n(&mut self, n: usize) -> Vec<M> {
+        let mut received = Vec::with_capacity(n);
+        let deadline = tokio::time::sleep(self.timeout);
+        tokio::pin!(deadline);
+        while received.len() < n {
+            tokio::select! {
+                Some(msg) = self.received.recv() => received.push(msg),
+                _ = &mut deadline => panic!("probe: {} received {} of {} messages within {:?}", self.id, received.len(), n, self.timeout),
+            }
+        }
+        received
+    }
+}
+
+impl<M: Message> Default for TestProbe<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Probe<M> {
+    id: ActorId,
+    sender: mpsc::UnboundedSender<M>,
+}
+
+#[async_trait::async_trait]
+impl<M: Message> Deliver<M, (), ActorError> for Probe<M> {
+    fn id(&self) -> &ActorId {
+        &self.id
+    }
+
+    async fn ask(&self, msg: M, _: Option<Duration>) -> Result<Result<(), ActorError>, ActorError> {
+        self.send(msg).map(Ok)
+    }
+
+    async fn tell(&self, msg: M) -> Result<Result<(), ActorError>, ActorError> {
+        self.send(msg).map(Ok)
+    }
+
+    fn send(&self, msg: M) -> Result<(), ActorError> {
+        self.sender.send(msg).map_err(|_| ActorError::CallBackSend)
+    }
+}
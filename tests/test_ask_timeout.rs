@@ -0,0 +1,91 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message};
+use lutetium::actor::refs::{DynRef, ErrorFlattenAction, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem};
+
+pub struct Stuck {
+    release: Arc<Notify>,
+    handled: u32,
+}
+
+impl Actor for Stuck { type Context = Context; }
+
+pub enum StuckCommand {
+    Block,
+    Handle,
+    Count,
+}
+
+impl Message for StuckCommand {}
+
+#[async_trait::async_trait]
+impl Handler<StuckCommand> for Stuck {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: StuckCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            StuckCommand::Block => {
+                self.release.notified().await;
+            }
+            StuckCommand::Handle => {
+                self.handled += 1;
+            }
+            StuckCommand::Count => {}
+        }
+        Ok(self.handled)
+    }
+}
+
+#[tokio::test]
+async fn ask_timeout_skips_abandoned_messages() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let release = Arc::new(Notify::new());
+    let refs = system.spawn(Uuid::now_v7(), Stuck { release: Arc::clone(&release), handled: 0 }).await?;
+
+    refs.send(StuckCommand::Block)?;
+
+    let res = RegularAction::ask_timeout(&refs, StuckCommand::Handle, Duration::from_millis(50)).await;
+    assert!(matches!(res, Err(ActorError::Timeout { .. })));
+
+    let res = ErrorFlattenAction::ask_timeout(&refs, StuckCommand::Handle, Duration::from_millis(50)).await;
+    assert!(matches!(res, Err(ActorError::Timeout { .. })));
+
+    release.notify_one();
+
+    assert_eq!(RegularAction::ask(&refs, StuckCommand::Count).await??, 0);
+
+    refs.shutdown().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn system_default_ask_timeout() -> anyhow::Result<()> {
+    let mut system = ActorSystem::builder();
+    system.ask_timeout(Duration::from_millis(50));
+    let system = system.build();
+
+    let release = Arc::new(Notify::new());
+    let refs = system.spawn(Uuid::now_v7(), Stuck { release: Arc::clone(&release), handled: 0 }).await?;
+
+    refs.send(StuckCommand::Block)?;
+
+    assert!(matches!(RegularAction::ask(&refs, StuckCommand::Handle).await, Err(ActorError::Timeout { .. })));
+    assert!(matches!(RegularAction::tell(&refs, StuckCommand::Handle).await, Err(ActorError::Timeout { .. })));
+
+    release.notify_one();
+
+    assert_eq!(RegularAction::ask(&refs, StuckCommand::Count).await??, 0);
+
+    refs.shutdown().await?;
+
+    Ok(())
+}
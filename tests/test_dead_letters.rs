@@ -0,0 +1,168 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, ActorContext, Context, Handler, Message};
+use lutetium::actor::refs::{MailboxConfig, OverflowPolicy, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, DeadLetter, DeadLetterListener, DeadLetterReason, LutetiumActorSystem, SpawnConfig};
+
+#[derive(Clone, Default)]
+pub struct Letters(Arc<Mutex<Vec<DeadLetter>>>);
+
+impl DeadLetterListener for Letters {
+    fn receive(&self, letter: DeadLetter) {
+        self.0.lock().unwrap().push(letter);
+    }
+}
+
+impl Letters {
+    fn take(&self) -> Vec<DeadLetter> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+pub struct Worker {
+    release: Arc<Notify>,
+}
+
+impl Actor for Worker { type Context = Context; }
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Block,
+    Quit,
+    Order(u32),
+}
+
+impl Message for Command {}
+
+#[async_trait::async_trait]
+impl Handler<Command> for Worker {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: Command, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            Command::Block => self.release.notified().await,
+            Command::Quit => ctx.shutdown().await,
+            Command::Order(_) => {}
+        }
+        Ok(())
+    }
+}
+
+fn system(letters: &Letters) -> ActorSystem {
+    let mut system = ActorSystem::builder();
+    system.dead_letters(letters.clone());
+    system.build()
+}
+
+#[tokio::test]
+async fn closed_mailbox() -> anyhow::Result<()> {
+    let letters = Letters::default();
+    let system = system(&letters);
+    let id = Uuid::now_v7();
+    let refs = system.spawn(id, Worker { release: Arc::new(Notify::new()) }).await?;
+
+    system.shutdown(&id).await?;
+    let before = SystemTime::now();
+    assert!(matches!(refs.ask(Command::Order(1)).await, Err(ActorError::CallBackSend)));
+
+    let letters = letters.take();
+    assert_eq!(letters.len(), 1);
+    assert_eq!(letters[0].target().to_string(), id.to_string());
+    assert!(letters[0].message().ends_with("Command"));
+    assert_eq!(letters[0].reason(), &DeadLetterReason::MailboxClosed);
+    assert!(letters[0].timestamp() >= before);
+    assert_eq!(letters[0].payload::<Command>(), Some(&Command::Order(1)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn full_mailbox() -> anyhow::Result<()> {
+    let letters = Letters::default();
+    let system = system(&letters);
+    let release = Arc::new(Notify::new());
+    let config = SpawnConfig::default()
+        .mailbox(MailboxConfig::bounded(1, OverflowPolicy::Fail));
+    let refs = system.spawn_with(Uuid::now_v7(), Worker { release: Arc::clone(&release) }, config).await?;
+
+    refs.send(Command::Block)?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    refs.send(Command::Order(1))?;
+    assert!(matches!(refs.send(Command::Order(2)), Err(ActorError::MailboxFull { .. })));
+
+    let letters = letters.take();
+    assert_eq!(letters.len(), 1);
+    assert_eq!(letters[0].reason(), &DeadLetterReason::MailboxFull);
+    assert_eq!(letters[0].payload::<Command>(), Some(&Command::Order(2)));
+
+    release.notify_one();
+    system.shutdown_all().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn unprocessed_on_stop() -> anyhow::Result<()> {
+    let letters = Letters::default();
+    let system = system(&letters);
+    let release = Arc::new(Notify::new());
+    let id = Uuid::now_v7();
+    let refs = system.spawn(id, Worker { release: Arc::clone(&release) }).await?;
+
+    refs.send(Command::Block)?;
+    refs.send(Command::Quit)?;
+    refs.send(Command::Order(1))?;
+    refs.send(Command::Order(2))?;
+    release.notify_one();
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!system.contains(id).await);
+
+    let letters = letters.take();
+    let orders = letters.iter()
+        .inspect(|letter| assert_eq!(letter.reason(), &DeadLetterReason::Unprocessed))
+        .filter_map(|letter| letter.payload::<Command>())
+        .collect::<Vec<_>>();
+    assert_eq!(orders, vec![&Command::Order(1), &Command::Order(2)]);
+    Ok(())
+}
+
+pub struct Refusing;
+
+#[async_trait::async_trait]
+impl Actor for Refusing {
+    type Context = Context;
+
+    async fn activate(&mut self, ctx: &mut Self::Context) -> Result<(), ActorError> {
+        ctx.myself::<Self>()?.send(Command::Order(1))?;
+        Err(ActorError::NotEnoughValue)
+    }
+}
+
+#[async_trait::async_trait]
+impl Handler<Command> for Refusing {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: Command, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn unprocessed_on_failed_activation() -> anyhow::Result<()> {
+    let letters = Letters::default();
+    let system = system(&letters);
+
+    assert!(system.spawn(Uuid::now_v7(), Refusing).await.is_err());
+
+    let letters = letters.take();
+    assert_eq!(letters.len(), 1);
+    assert_eq!(letters[0].reason(), &DeadLetterReason::Unprocessed);
+    assert_eq!(letters[0].payload::<Command>(), Some(&Command::Order(1)));
+    Ok(())
+}
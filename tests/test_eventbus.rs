@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, ActorContext, Context, Extension, FromContext, Handler, Message};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, EventBus, LutetiumActorSystem};
+
+#[derive(Clone)]
+pub struct OrderPlaced(u32);
+
+impl Message for OrderPlaced {}
+
+#[derive(Default)]
+pub struct Ledger {
+    amount: u32,
+}
+
+#[async_trait::async_trait]
+impl Actor for Ledger {
+    type Context = Context;
+
+    async fn activate(&mut self, ctx: &mut Self::Context) -> Result<(), ActorError> {
+        let Extension(bus): Extension<EventBus> = Extension::from_context(ctx).await?;
+        bus.subscribe::<Self, OrderPlaced>(&ctx.myself()?);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Handler<OrderPlaced> for Ledger {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: OrderPlaced, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.amount += msg.0;
+        Ok(())
+    }
+}
+
+pub struct Amount;
+
+impl Message for Amount {}
+
+#[async_trait::async_trait]
+impl Handler<Amount> for Ledger {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: Amount, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.amount)
+    }
+}
+
+#[tokio::test]
+async fn publish_to_subscribers() -> anyhow::Result<()> {
+    let bus = EventBus::default();
+    let mut system = ActorSystem::builder();
+    system.extension({
+        let bus = bus.clone();
+        move |ext| { ext.install(bus); }
+    });
+    let system = system.build();
+
+    let first = system.spawn(Uuid::now_v7(), Ledger::default()).await?;
+    let second = system.spawn(Uuid::now_v7(), Ledger::default()).await?;
+    assert_eq!(bus.subscribers::<OrderPlaced>(), 2);
+
+    assert_eq!(bus.publish(OrderPlaced(10)), 2);
+    assert_eq!(bus.publish(OrderPlaced(5)), 2);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    assert_eq!(first.ask(Amount).await??, 15);
+    assert_eq!(second.ask(Amount).await??, 15);
+
+    first.shutdown().await?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    assert_eq!(bus.subscribers::<OrderPlaced>(), 1);
+    assert_eq!(bus.publish(OrderPlaced(1)), 1);
+
+    bus.unsubscribe::<_, OrderPlaced>(&second);
+    assert_eq!(bus.publish(OrderPlaced(1)), 0);
+
+    second.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn recreated_subscriber_stays_subscribed() -> anyhow::Result<()> {
+    let bus = EventBus::default();
+    let mut system = ActorSystem::builder();
+    system.extension({
+        let bus = bus.clone();
+        move |ext| { ext.install(bus); }
+    });
+    let system = system.build();
+
+    let id = Uuid::now_v7();
+    system.spawn(id, Ledger::default()).await?;
+    system.shutdown(&id).await?;
+    let recreated = system.spawn(id, Ledger::default()).await?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    assert_eq!(bus.subscribers::<OrderPlaced>(), 1);
+    assert_eq!(bus.publish(OrderPlaced(3)), 1);
+    assert_eq!(recreated.ask(Amount).await??, 3);
+
+    recreated.shutdown().await?;
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, ActorContext, Context, Handler, Message, StopReason, SupervisorStrategy};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem, SpawnConfig};
+
+type Journal = Arc<Mutex<Vec<String>>>;
+
+pub struct Order {
+    id: Uuid,
+    items: u32,
+    journal: Journal,
+}
+
+#[async_trait::async_trait]
+impl Actor for Order {
+    type Context = Context;
+
+    async fn activate(&mut self, ctx: &mut Self::Context) -> Result<(), ActorError> {
+        for no in 0..self.items {
+            let item = LineItem { no, journal: Arc::clone(&self.journal) };
+            let config = SpawnConfig::default()
+                .supervisor(SupervisorStrategy::escalate());
+            ctx.spawn_child_with(format!("{}/{}", self.id, no), item, config).await?;
+        }
+        Ok(())
+    }
+
+    async fn deactivate(&mut self, reason: StopReason, _ctx: &mut Self::Context) -> Result<(), ActorError> {
+        self.journal.lock().unwrap().push(format!("order {:?}", reason));
+        Ok(())
+    }
+}
+
+pub struct Children;
+
+impl Message for Children {}
+
+#[async_trait::async_trait]
+impl Handler<Children> for Order {
+    type Accept = usize;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: Children, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(ctx.children().len())
+    }
+}
+
+pub struct LineItem {
+    no: u32,
+    journal: Journal,
+}
+
+#[async_trait::async_trait]
+impl Actor for LineItem {
+    type Context = Context;
+
+    async fn deactivate(&mut self, reason: StopReason, _ctx: &mut Self::Context) -> Result<(), ActorError> {
+        self.journal.lock().unwrap().push(format!("item {} {:?}", self.no, reason));
+        Ok(())
+    }
+}
+
+pub struct Break;
+
+impl Message for Break {}
+
+#[async_trait::async_trait]
+impl Handler<Break> for LineItem {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: Break, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Err(ActorError::NotEnoughValue)
+    }
+}
+
+/// Ask the parent Actor, after the parent has started to shutdown.
+pub struct Consult(Uuid);
+
+impl Message for Consult {}
+
+#[async_trait::async_trait]
+impl Handler<Consult> for LineItem {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, Consult(parent): Consult, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let answer = ctx.system().find::<Order>(parent).await?.ask(Children).await;
+        self.journal.lock().unwrap().push(format!("item {} consulted: {}", self.no, answer.is_ok()));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn parent_stops_children_first() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let journal = Journal::default();
+
+    let id = Uuid::now_v7();
+    let order = system.spawn(id, Order { id, items: 2, journal: Arc::clone(&journal) }).await?;
+    assert_eq!(order.ask(Children).await??, 2);
+
+    system.shutdown(&id).await?;
+
+    {
+        let journal = journal.lock().unwrap();
+        assert_eq!(journal.len(), 3);
+        assert!(journal[..2].iter().all(|entry| entry.ends_with("Parent")));
+        assert_eq!(journal[2], "order Terminated");
+    }
+
+    assert!(matches!(system.find::<LineItem>(format!("{}/0", id)).await, Err(ActorError::NotFoundActor { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn escalate_to_parent() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let journal = Journal::default();
+
+    let id = Uuid::now_v7();
+    let order = system.spawn(id, Order { id, items: 2, journal: Arc::clone(&journal) }).await?;
+
+    let item = system.find::<LineItem>(format!("{}/0", id)).await?;
+    assert!(item.ask(Break).await?.is_err());
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(!item.is_active().await);
+    assert!(order.is_active().await);
+    assert_eq!(order.ask(Children).await??, 1);
+
+    order.shutdown().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn parent_supervisor_decides_escalation() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let journal = Journal::default();
+
+    let id = Uuid::now_v7();
+    let config = SpawnConfig::default()
+        .supervisor(SupervisorStrategy::stop());
+    let order = system.spawn_with(id, Order { id, items: 2, journal: Arc::clone(&journal) }, config).await?;
+
+    let item = system.find::<LineItem>(format!("{}/0", id)).await?;
+    assert!(item.ask(Break).await?.is_err());
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(!order.is_active().await);
+    assert!(matches!(system.find::<LineItem>(format!("{}/1", id)).await, Err(ActorError::NotFoundActor { .. })));
+
+    let journal = journal.lock().unwrap();
+    assert!(journal.contains(&"item 0 Supervisor".to_string()));
+    assert!(journal.contains(&"item 1 Parent".to_string()));
+    assert_eq!(journal.last().unwrap(), "order Supervisor");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn child_asks_parent_while_parent_stops() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let journal = Journal::default();
+
+    let id = Uuid::now_v7();
+    system.spawn(id, Order { id, items: 1, journal: Arc::clone(&journal) }).await?;
+
+    let item = system.find::<LineItem>(format!("{}/0", id)).await?;
+    item.send(Consult(id))?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    tokio::time::timeout(Duration::from_secs(2), system.shutdown(&id)).await??;
+
+    let journal = journal.lock().unwrap();
+    assert_eq!(journal.as_slice(), ["item 0 consulted: false", "item 0 Parent", "order Terminated"]);
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, Interceptor, Invocation, LutetiumActorSystem, SpawnConfig};
+
+#[derive(Clone, Default)]
+pub struct Audit {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait::async_trait]
+impl Interceptor for Audit {
+    async fn before(&self, invocation: &Invocation) -> Result<(), ActorError> {
+        self.log.lock().unwrap().push(format!("{} before {}", self.name, invocation.message.rsplit("::").next().unwrap()));
+        Ok(())
+    }
+
+    async fn after(&self, invocation: &Invocation, _elapsed: Duration, result: &Result<(), ActorError>) {
+        self.log.lock().unwrap().push(format!("{} after {} {}", self.name, invocation.message.rsplit("::").next().unwrap(), result.is_ok()));
+    }
+}
+
+pub struct Guard;
+
+#[async_trait::async_trait]
+impl Interceptor for Guard {
+    async fn before(&self, invocation: &Invocation) -> Result<(), ActorError> {
+        if invocation.message.ends_with("Forbidden") {
+            return Err(ActorError::NotEnoughValue);
+        }
+        Ok(())
+    }
+}
+
+pub struct Vault {
+    opened: u32,
+}
+
+impl Actor for Vault { type Context = Context; }
+
+pub struct Open;
+
+impl Message for Open {}
+
+pub struct Forbidden;
+
+impl Message for Forbidden {}
+
+#[async_trait::async_trait]
+impl Handler<Open> for Vault {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _msg: Open, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.opened += 1;
+        Ok(self.opened)
+    }
+}
+
+#[async_trait::async_trait]
+impl Handler<Forbidden> for Vault {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _msg: Forbidden, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        panic!("forbidden message reached the handler");
+    }
+}
+
+#[tokio::test]
+async fn layers_wrap_handler() -> anyhow::Result<()> {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut system = ActorSystem::builder();
+    system.interceptor(Audit { name: "system", log: Arc::clone(&log) });
+    let system = system.build();
+
+    let config = SpawnConfig::default()
+        .interceptor(Audit { name: "actor", log: Arc::clone(&log) });
+    let refs = system.spawn_with(Uuid::now_v7(), Vault { opened: 0 }, config).await?;
+
+    assert_eq!(refs.ask(Open).await??, 1);
+
+    assert_eq!(*log.lock().unwrap(), vec![
+        "system before Open",
+        "actor before Open",
+        "actor after Open true",
+        "system after Open true",
+    ]);
+
+    refs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn refuse_before_handler() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let config = SpawnConfig::default()
+        .interceptor(Guard);
+    let refs = system.spawn_with(Uuid::now_v7(), Vault { opened: 0 }, config).await?;
+
+    assert!(matches!(refs.ask(Forbidden).await, Err(ActorError::NotEnoughValue)));
+    assert_eq!(refs.ask(Open).await??, 1);
+    assert!(refs.is_active().await);
+
+    refs.shutdown().await?;
+    Ok(())
+}
+
+pub struct Lockdown;
+
+#[async_trait::async_trait]
+impl Interceptor for Lockdown {
+    async fn before(&self, _invocation: &Invocation) -> Result<(), ActorError> {
+        Err(ActorError::NotEnoughValue)
+    }
+}
+
+#[tokio::test]
+async fn shutdown_is_not_intercepted() -> anyhow::Result<()> {
+    let mut system = ActorSystem::builder();
+    system.interceptor(Lockdown);
+    let system = system.build();
+
+    let id = Uuid::now_v7();
+    let refs = system.spawn(id, Vault { opened: 0 }).await?;
+    assert!(matches!(refs.ask(Open).await, Err(ActorError::NotEnoughValue)));
+
+    system.shutdown(&id).await?;
+    assert!(!refs.is_active().await);
+    Ok(())
+}
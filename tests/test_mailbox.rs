@@ -0,0 +1,149 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message};
+use lutetium::actor::refs::{ActorRef, DynRef, MailboxConfig, OverflowPolicy, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem, SpawnConfig};
+
+pub struct Gate {
+    entered: Arc<Notify>,
+    release: Arc<Notify>,
+}
+
+impl Actor for Gate { type Context = Context; }
+
+pub enum GateCommand {
+    Block,
+    Pass(u32),
+}
+
+impl Message for GateCommand {}
+
+#[async_trait::async_trait]
+impl Handler<GateCommand> for Gate {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: GateCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            GateCommand::Block => {
+                self.entered.notify_one();
+                self.release.notified().await;
+                Ok(0)
+            }
+            GateCommand::Pass(n) => Ok(n),
+        }
+    }
+}
+
+/// Spawn a `Gate` with a mailbox of one, and keep it busy with a blocked message.
+async fn blocked(system: &ActorSystem, overflow: OverflowPolicy) -> anyhow::Result<(ActorRef<Gate>, Arc<Notify>)> {
+    let entered = Arc::new(Notify::new());
+    let release = Arc::new(Notify::new());
+    let gate = Gate { entered: Arc::clone(&entered), release: Arc::clone(&release) };
+
+    let config = SpawnConfig::default()
+        .mailbox(MailboxConfig::bounded(1, overflow));
+    let refs = system.spawn_with(Uuid::now_v7(), gate, config).await?;
+
+    let blocking = refs.clone();
+    tokio::spawn(async move { blocking.ask(GateCommand::Block).await });
+    entered.notified().await;
+
+    Ok((refs, release))
+}
+
+#[tokio::test]
+async fn fail_when_full() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (refs, release) = blocked(&system, OverflowPolicy::Fail).await?;
+
+    let queued = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(GateCommand::Pass(1)).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert!(matches!(refs.ask(GateCommand::Pass(2)).await, Err(ActorError::MailboxFull { .. })));
+
+    release.notify_one();
+    assert_eq!(queued.await??.unwrap(), 1);
+
+    refs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn drop_oldest_when_full() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (refs, release) = blocked(&system, OverflowPolicy::DropOldest).await?;
+
+    let oldest = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(GateCommand::Pass(1)).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let newest = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(GateCommand::Pass(2)).await }
+    });
+
+    assert!(matches!(oldest.await?, Err(ActorError::MailboxFull { .. })));
+
+    release.notify_one();
+    assert_eq!(newest.await??.unwrap(), 2);
+
+    refs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn drop_newest_when_full() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (refs, release) = blocked(&system, OverflowPolicy::DropNewest).await?;
+
+    let oldest = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(GateCommand::Pass(1)).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert!(matches!(refs.ask(GateCommand::Pass(2)).await, Err(ActorError::MailboxFull { .. })));
+
+    release.notify_one();
+    assert_eq!(oldest.await??.unwrap(), 1);
+
+    refs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn wait_when_full() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (refs, release) = blocked(&system, OverflowPolicy::Wait).await?;
+
+    let first = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(GateCommand::Pass(1)).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let second = tokio::spawn({
+        let refs = refs.clone();
+        async move { refs.ask(GateCommand::Pass(2)).await }
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!second.is_finished());
+
+    release.notify_one();
+    assert_eq!(first.await??.unwrap(), 1);
+    assert_eq!(second.await??.unwrap(), 2);
+
+    refs.shutdown().await?;
+    Ok(())
+}
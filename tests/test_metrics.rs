@@ -0,0 +1,86 @@
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message, SupervisorStrategy};
+use lutetium::actor::refs::RegularAction;
+use lutetium::system::{ActorSystem, LutetiumActorSystem, Metrics, SpawnConfig};
+
+#[derive(Debug, Default)]
+pub struct Counter {
+    count: u32
+}
+
+impl Actor for Counter { type Context = Context; }
+
+pub enum CounterCommand {
+    Increment,
+    Fail,
+}
+
+impl Message for CounterCommand {}
+
+#[derive(Debug, thiserror::Error)]
+#[error("counter failed.")]
+pub struct CounterError;
+
+#[async_trait::async_trait]
+impl Handler<CounterCommand> for Counter {
+    type Accept = u32;
+    type Rejection = CounterError;
+
+    async fn call(&mut self, msg: CounterCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            CounterCommand::Increment => {
+                self.count += 1;
+                Ok(self.count)
+            }
+            CounterCommand::Fail => Err(CounterError),
+        }
+    }
+}
+
+#[tokio::test]
+async fn collect_per_actor_type() -> anyhow::Result<()> {
+    let metrics = Metrics::default();
+    let mut builder = ActorSystem::builder();
+    builder.extension(|ext| {
+        ext.install(metrics.clone());
+    });
+    let system = builder.build();
+
+    let config = SpawnConfig::default()
+        .supervisor(SupervisorStrategy::restart(Counter::default));
+    let first = system.spawn_with(Uuid::now_v7(), Counter::default(), config).await?;
+    let second = system.spawn(Uuid::now_v7(), Counter::default()).await?;
+
+    first.ask(CounterCommand::Increment).await??;
+    second.ask(CounterCommand::Increment).await??;
+    assert!(first.ask(CounterCommand::Fail).await?.is_err());
+
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.len(), 1);
+    let counter = &snapshot[0];
+    assert!(counter.actor_type.ends_with("Counter"));
+    assert_eq!(counter.live, 2);
+    assert_eq!(counter.mailbox_depth, 0);
+    assert_eq!(counter.processed, 3);
+    assert_eq!(counter.errors, 1);
+    assert_eq!(counter.restarts, 1);
+    assert_eq!(counter.latency.count, 3);
+    assert_eq!(counter.latency.buckets.last().map(|(_, count)| *count), Some(3));
+
+    let rendered = metrics.render_prometheus();
+    assert!(rendered.contains("# TYPE lutetium_handler_duration_seconds histogram"));
+    assert!(rendered.contains(&format!("lutetium_actors_live{{actor_type=\"{}\"}} 2", counter.actor_type)));
+    assert!(rendered.contains(&format!("lutetium_handler_errors_total{{actor_type=\"{}\"}} 1", counter.actor_type)));
+    assert!(rendered.contains(&format!("lutetium_handler_duration_seconds_count{{actor_type=\"{}\"}} 3", counter.actor_type)));
+
+    system.shutdown_all().await?;
+
+    let counter = &metrics.snapshot()[0];
+    assert_eq!(counter.live, 0);
+    assert_eq!(counter.mailbox_depth, 0);
+    assert_eq!(counter.processed, 3);
+    assert_eq!(counter.errors, 1);
+    assert_eq!(counter.latency.count, 3);
+    Ok(())
+}
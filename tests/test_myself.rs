@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, ActorContext, Context, Handler, Message};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem};
+
+pub struct Echo {
+    greeted: bool,
+}
+
+#[async_trait::async_trait]
+impl Actor for Echo {
+    type Context = Context;
+
+    async fn activate(&mut self, ctx: &mut Self::Context) -> Result<(), ActorError> {
+        ctx.myself::<Echo>()?.send(EchoCommand::Greet)?;
+        Ok(())
+    }
+}
+
+pub struct Other;
+
+impl Actor for Other { type Context = Context; }
+
+pub enum EchoCommand {
+    Greet,
+    Greeted,
+    Mistyped,
+}
+
+impl Message for EchoCommand {}
+
+#[async_trait::async_trait]
+impl Handler<EchoCommand> for Echo {
+    type Accept = bool;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: EchoCommand, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            EchoCommand::Greet => self.greeted = true,
+            EchoCommand::Greeted => {}
+            EchoCommand::Mistyped => return ctx.myself::<Other>().map(|_| false),
+        }
+        Ok(self.greeted)
+    }
+}
+
+#[tokio::test]
+async fn send_to_myself_from_activate() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let refs = system.spawn(Uuid::now_v7(), Echo { greeted: false }).await?;
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert!(refs.ask(EchoCommand::Greeted).await??);
+
+    assert!(matches!(refs.ask(EchoCommand::Mistyped).await?, Err(ActorError::TypeMismatch { .. })));
+
+    refs.shutdown().await?;
+    Ok(())
+}
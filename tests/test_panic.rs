@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message, SupervisorStrategy};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem, SpawnConfig};
+
+pub struct Fragile;
+
+impl Actor for Fragile { type Context = Context; }
+
+pub enum FragileCommand {
+    Ping,
+    Explode,
+}
+
+impl Message for FragileCommand {}
+
+#[async_trait::async_trait]
+impl Handler<FragileCommand> for Fragile {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: FragileCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            FragileCommand::Ping => Ok(()),
+            FragileCommand::Explode => panic!("exploded"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn panic_is_delivered_and_actor_stopped() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let id = Uuid::now_v7();
+    let refs = system.spawn(id, Fragile).await?;
+
+    let res = refs.ask(FragileCommand::Explode).await;
+    assert!(matches!(res, Err(ActorError::Panicked { ref reason, .. }) if reason == "exploded"));
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(!refs.is_active().await);
+    assert!(matches!(system.find::<Fragile>(id).await, Err(ActorError::NotFoundActor { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn panic_resumed_by_policy() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let config = SpawnConfig::default()
+        .supervisor(SupervisorStrategy::resume());
+    let refs = system.spawn_with(Uuid::now_v7(), Fragile, config).await?;
+
+    assert!(matches!(refs.tell(FragileCommand::Explode).await, Err(ActorError::Panicked { .. })));
+
+    refs.ask(FragileCommand::Ping).await??;
+
+    refs.shutdown().await?;
+
+    Ok(())
+}
+
+pub struct Brittle {
+    explode_on_activate: bool,
+}
+
+#[async_trait::async_trait]
+impl Actor for Brittle {
+    type Context = Context;
+
+    async fn activate(&mut self, _ctx: &mut Context) -> Result<(), ActorError> {
+        if self.explode_on_activate {
+            panic!("exploded while activating");
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Handler<FragileCommand> for Brittle {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: FragileCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            FragileCommand::Ping => Ok(()),
+            FragileCommand::Explode => panic!("exploded"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn panic_while_activating_restarted_actor() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let id = Uuid::now_v7();
+    let config = SpawnConfig::default()
+        .supervisor(SupervisorStrategy::restart(|| Brittle { explode_on_activate: true }));
+    let refs = system.spawn_with(id, Brittle { explode_on_activate: false }, config).await?;
+
+    assert!(matches!(refs.ask(FragileCommand::Explode).await, Err(ActorError::Panicked { .. })));
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(!refs.is_active().await);
+    assert!(!system.contains(id).await);
+
+    let again = system.spawn(id, Brittle { explode_on_activate: false }).await?;
+    again.ask(FragileCommand::Ping).await??;
+    again.shutdown().await?;
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message, StopReason};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem, SpawnConfig};
+
+pub struct Entity {
+    visits: u32,
+    stopped: Arc<Mutex<Vec<StopReason>>>,
+}
+
+#[async_trait::async_trait]
+impl Actor for Entity {
+    type Context = Context;
+
+    async fn deactivate(&mut self, reason: StopReason, _ctx: &mut Self::Context) -> Result<(), ActorError> {
+        self.stopped.lock().unwrap().push(reason);
+        Ok(())
+    }
+
+    fn idle_timeout() -> Option<Duration> {
+        Some(Duration::from_secs(30))
+    }
+}
+
+pub struct Visit;
+
+impl Message for Visit {}
+
+#[async_trait::async_trait]
+impl Handler<Visit> for Entity {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: Visit, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.visits += 1;
+        Ok(self.visits)
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn passivate_idle_actor() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let stopped = Arc::new(Mutex::new(Vec::new()));
+
+    let id = Uuid::now_v7();
+    let refs = system.spawn(id, Entity { visits: 0, stopped: Arc::clone(&stopped) }).await?;
+
+    tokio::time::sleep(Duration::from_secs(20)).await;
+    assert_eq!(refs.ask(Visit).await??, 1);
+
+    tokio::time::sleep(Duration::from_secs(20)).await;
+    assert!(refs.is_active().await);
+
+    tokio::time::sleep(Duration::from_secs(15)).await;
+    assert!(!refs.is_active().await);
+    assert_eq!(*stopped.lock().unwrap(), vec![StopReason::Passivated]);
+    assert!(matches!(system.find::<Entity>(id).await, Err(ActorError::NotFoundActor { .. })));
+
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn find_or_recreates_passivated_actor() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let stopped = Arc::new(Mutex::new(Vec::new()));
+
+    let id = Uuid::now_v7();
+    let spawn = |stopped: &Arc<Mutex<Vec<StopReason>>>| {
+        let stopped = Arc::clone(stopped);
+        move |_| async move { Entity { visits: 0, stopped } }
+    };
+
+    let refs = system.find_or(id, spawn(&stopped)).await?;
+    assert_eq!(refs.ask(Visit).await??, 1);
+
+    tokio::time::sleep(Duration::from_secs(31)).await;
+
+    let refs = system.find_or(id, spawn(&stopped)).await?;
+    assert_eq!(refs.ask(Visit).await??, 1);
+
+    refs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn idle_timeout_at_spawn() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let stopped = Arc::new(Mutex::new(Vec::new()));
+
+    let config = SpawnConfig::default()
+        .idle_timeout(Duration::from_secs(5));
+    let refs = system.spawn_with(Uuid::now_v7(), Entity { visits: 0, stopped: Arc::clone(&stopped) }, config).await?;
+
+    tokio::time::sleep(Duration::from_secs(6)).await;
+    assert!(!refs.is_active().await);
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use lutetium::actor::{Actor, Context, Handler, Message};
+use lutetium::actor::refs::RegularAction;
+use lutetium::errors::ActorError;
+use lutetium::identifier::{ActorPath, ActorSelection};
+use lutetium::system::{ActorSystem, LutetiumActorSystem};
+
+#[derive(Default)]
+pub struct Order {
+    touched: u32,
+}
+
+impl Actor for Order { type Context = Context; }
+
+#[derive(Clone)]
+pub struct Touch;
+
+impl Message for Touch {}
+
+#[async_trait::async_trait]
+impl Handler<Touch> for Order {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: Touch, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.touched += 1;
+        Ok(self.touched)
+    }
+}
+
+#[tokio::test]
+async fn select_by_path() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let orders = ActorPath::parse("/user/orders")?;
+    for no in 0..3 {
+        system.spawn(orders.child(no.to_string())?, Order::default()).await?;
+    }
+    system.spawn("/user/carts/0", Order::default()).await?;
+    system.spawn("plain", Order::default()).await?;
+
+    let selection = ActorSelection::parse("/user/orders/*")?;
+    assert_eq!(system.select::<Order>(&selection).await.len(), 3);
+    assert_eq!(system.broadcast::<Order, _>(&selection, Touch).await, 3);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let order = system.find::<Order>("/user/orders/1").await?;
+    assert_eq!(order.ask(Touch).await??, 2);
+    let cart = system.find::<Order>("/user/carts/0").await?;
+    assert_eq!(cart.ask(Touch).await??, 1);
+
+    let report = system.shutdown_selection(&ActorSelection::descendants_of(&orders)).await?;
+    assert_eq!(report.stopped().len(), 3);
+    assert!(report.is_complete());
+    assert_eq!(system.count().await, 2);
+    assert!(system.contains("plain").await);
+
+    system.shutdown_all().await?;
+    Ok(())
+}
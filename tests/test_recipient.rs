@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message};
+use lutetium::actor::refs::Recipient;
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem};
+
+#[derive(Clone)]
+pub struct OrderPlaced(u32);
+
+impl Message for OrderPlaced {}
+
+pub struct Total;
+
+impl Message for Total {}
+
+#[derive(Default)]
+pub struct Mailer {
+    sent: u32,
+}
+
+impl Actor for Mailer { type Context = Context; }
+
+#[async_trait::async_trait]
+impl Handler<OrderPlaced> for Mailer {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: OrderPlaced, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.sent += 1;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Handler<Total> for Mailer {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: Total, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.sent)
+    }
+}
+
+#[derive(Default)]
+pub struct Ledger {
+    amount: u32,
+}
+
+impl Actor for Ledger { type Context = Context; }
+
+#[async_trait::async_trait]
+impl Handler<OrderPlaced> for Ledger {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: OrderPlaced, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.amount += msg.0;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Handler<Total> for Ledger {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: Total, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(self.amount)
+    }
+}
+
+#[derive(Default)]
+pub struct Audit {
+    seen: u32,
+}
+
+impl Actor for Audit { type Context = Context; }
+
+#[derive(Debug)]
+pub struct Refused;
+
+#[async_trait::async_trait]
+impl Handler<OrderPlaced> for Audit {
+    type Accept = u32;
+    type Rejection = Refused;
+
+    async fn call(&mut self, msg: OrderPlaced, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if msg.0 == 0 {
+            return Err(Refused);
+        }
+        self.seen += 1;
+        Ok(self.seen)
+    }
+}
+
+#[tokio::test]
+async fn heterogeneous_recipients() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let mailer = system.spawn(Uuid::now_v7(), Mailer::default()).await?;
+    let ledger = system.spawn(Uuid::now_v7(), Ledger::default()).await?;
+
+    let subscribers: Vec<Recipient<OrderPlaced>> = vec![mailer.recipient(), ledger.clone().into()];
+
+    for subscriber in &subscribers {
+        subscriber.ask(OrderPlaced(10)).await??;
+        subscriber.tell(OrderPlaced(20)).await??;
+        subscriber.send(OrderPlaced(30))?;
+    }
+
+    let totals: Vec<Recipient<Total, u32>> = vec![mailer.recipient(), ledger.recipient()];
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    assert_eq!(totals[0].ask(Total).await??, 3);
+    assert_eq!(totals[1].ask_timeout(Total, Duration::from_secs(1)).await??, 60);
+    assert_eq!(subscribers[1].id(), totals[1].id());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn erased_recipients() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let mailer = system.spawn(Uuid::now_v7(), Mailer::default()).await?;
+    let audit = system.spawn(Uuid::now_v7(), Audit::default()).await?;
+
+    let subscribers: Vec<Recipient<OrderPlaced>> = vec![mailer.recipient(), audit.recipient().erase()];
+
+    for subscriber in &subscribers {
+        subscriber.ask(OrderPlaced(10)).await??;
+        subscriber.tell(OrderPlaced(20)).await??;
+        subscriber.send(OrderPlaced(30))?;
+    }
+
+    let rejected = subscribers[1].ask(OrderPlaced(0)).await?;
+    assert!(matches!(rejected, Err(ActorError::Rejected { .. })));
+    assert_eq!(subscribers[1].id(), audit.recipient::<OrderPlaced>().id());
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use tokio::task::JoinSet;
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem};
+
+pub struct Entity {
+    activated: Arc<AtomicU32>,
+    fail: bool,
+}
+
+#[async_trait::async_trait]
+impl Actor for Entity {
+    type Context = Context;
+
+    async fn activate(&mut self, _ctx: &mut Self::Context) -> Result<(), ActorError> {
+        self.activated.fetch_add(1, Ordering::SeqCst);
+        tokio::task::yield_now().await;
+        if self.fail {
+            return Err(ActorError::NotEnoughValue);
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_spawns_register_once() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let activated = Arc::new(AtomicU32::new(0));
+    let id = Uuid::now_v7();
+
+    let mut spawns = JoinSet::new();
+    for _ in 0..32 {
+        let system = system.clone();
+        let activated = Arc::clone(&activated);
+        spawns.spawn(async move {
+            system.spawn(id, Entity { activated, fail: false }).await
+        });
+    }
+
+    let mut registered = 0;
+    while let Some(spawned) = spawns.join_next().await {
+        match spawned? {
+            Ok(_) => registered += 1,
+            Err(e) => assert!(matches!(e, ActorError::AlreadySpawned { .. })),
+        }
+    }
+
+    assert_eq!(registered, 1);
+    assert_eq!(activated.load(Ordering::SeqCst), 1);
+
+    system.shutdown(&id).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_activation_releases_id() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let activated = Arc::new(AtomicU32::new(0));
+    let id = Uuid::now_v7();
+
+    let failed = system.spawn(id, Entity { activated: Arc::clone(&activated), fail: true }).await;
+    assert!(matches!(failed, Err(ActorError::NotEnoughValue)));
+    assert!(matches!(system.find::<Entity>(id).await, Err(ActorError::NotFoundActor { .. })));
+
+    system.spawn(id, Entity { activated: Arc::clone(&activated), fail: false }).await?;
+    assert_eq!(activated.load(Ordering::SeqCst), 2);
+
+    system.shutdown(&id).await?;
+    Ok(())
+}
+
+pub struct Other;
+
+impl Actor for Other { type Context = Context; }
+
+#[tokio::test]
+async fn introspection() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let activated = Arc::new(AtomicU32::new(0));
+
+    let first = Uuid::now_v7();
+    let second = Uuid::now_v7();
+    let other = Uuid::now_v7();
+    system.spawn(first, Entity { activated: Arc::clone(&activated), fail: false }).await?;
+    system.spawn(second, Entity { activated: Arc::clone(&activated), fail: false }).await?;
+    system.spawn(other, Other).await?;
+
+    assert_eq!(system.count().await, 3);
+    assert!(system.contains(other).await);
+    assert!(!system.contains(Uuid::now_v7()).await);
+
+    let mut ids = system.ids().await.iter().map(ToString::to_string).collect::<Vec<_>>();
+    ids.sort();
+    let mut expected = vec![first.to_string(), second.to_string(), other.to_string()];
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    assert_eq!(system.actors_of::<Entity>().await.len(), 2);
+    assert_eq!(system.actors_of::<Other>().await.len(), 1);
+
+    system.shutdown(&other).await?;
+    assert!(!system.contains(other).await);
+    assert_eq!(system.count().await, 2);
+
+    system.shutdown_all().await?;
+    assert_eq!(system.count().await, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn ids_are_namespaced_by_type() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let activated = Arc::new(AtomicU32::new(0));
+    let id = Uuid::now_v7();
+
+    system.spawn(id, Entity { activated: Arc::clone(&activated), fail: false }).await?;
+    system.spawn(id, Other).await?;
+    assert!(matches!(system.spawn(id, Other).await, Err(ActorError::AlreadySpawned { .. })));
+
+    assert_eq!(system.count().await, 2);
+    assert_eq!(system.ids().await.len(), 1);
+    system.find::<Entity>(id).await?;
+    system.find::<Other>(id).await?;
+
+    system.shutdown_of::<Entity>(&id).await?;
+    assert_eq!(system.count().await, 1);
+
+    let Err(ActorError::TypeMismatch { expected, actual, .. }) = system.find::<Entity>(id).await else {
+        panic!("expected a type mismatch");
+    };
+    assert!(expected.ends_with("Entity"));
+    assert!(actual.ends_with("Other"));
+    assert!(matches!(system.shutdown_of::<Entity>(&id).await, Err(ActorError::TypeMismatch { .. })));
+
+    system.spawn(id, Entity { activated: Arc::clone(&activated), fail: false }).await?;
+    let report = system.shutdown_all_timeout(Duration::from_secs(1)).await?;
+    assert_eq!(report.stopped().len(), 2);
+    assert!(report.is_complete());
+    assert!(!system.contains(id).await);
+    Ok(())
+}
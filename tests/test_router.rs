@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, ActorContext, Context, Directive, Handler, Message, SupervisorStrategy};
+use lutetium::actor::refs::RegularAction;
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem, Routing};
+
+pub struct Worker {
+    handled: u32,
+    release: Arc<Notify>,
+    refuse: Arc<AtomicBool>,
+}
+
+impl Worker {
+    fn factory(release: &Arc<Notify>, refuse: &Arc<AtomicBool>) -> impl Fn() -> Worker + Sync + Send + 'static {
+        let release = Arc::clone(release);
+        let refuse = Arc::clone(refuse);
+        move || Worker { handled: 0, release: Arc::clone(&release), refuse: Arc::clone(&refuse) }
+    }
+}
+
+#[async_trait::async_trait]
+impl Actor for Worker {
+    type Context = Context;
+
+    async fn activate(&mut self, _ctx: &mut Self::Context) -> Result<(), ActorError> {
+        if self.refuse.swap(false, Ordering::SeqCst) {
+            return Err(ActorError::NotEnoughValue);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub enum Job {
+    Who,
+    Handled,
+    Block,
+    Crash,
+}
+
+impl Message for Job {}
+
+#[async_trait::async_trait]
+impl Handler<Job> for Worker {
+    type Accept = (String, u32);
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: Job, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            Job::Who => self.handled += 1,
+            Job::Handled => {}
+            Job::Block => self.release.notified().await,
+            Job::Crash => panic!("worker crashed"),
+        }
+        Ok((ctx.id().to_string(), self.handled))
+    }
+}
+
+fn defaults() -> (Arc<Notify>, Arc<AtomicBool>) {
+    (Arc::new(Notify::new()), Arc::new(AtomicBool::new(false)))
+}
+
+#[tokio::test]
+async fn round_robin() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (release, refuse) = defaults();
+    let router = system.spawn_pool(Uuid::now_v7(), 3, Routing::RoundRobin, Worker::factory(&release, &refuse)).await?;
+
+    let mut hits = HashMap::new();
+    for _ in 0..6 {
+        let (who, _) = router.ask(Job::Who).await??;
+        *hits.entry(who).or_insert(0) += 1;
+    }
+
+    assert_eq!(hits.len(), 3);
+    assert!(hits.values().all(|hit| *hit == 2));
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn random() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (release, refuse) = defaults();
+    let router = system.spawn_pool(Uuid::now_v7(), 3, Routing::Random, Worker::factory(&release, &refuse)).await?;
+
+    let mut ids = Vec::new();
+    for member in router.members() {
+        ids.push(member.ask(Job::Handled).await??.0);
+    }
+
+    for _ in 0..10 {
+        let (who, _) = router.ask(Job::Who).await??;
+        assert!(ids.contains(&who));
+    }
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn smallest_mailbox() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (release, refuse) = defaults();
+    let router = system.spawn_pool(Uuid::now_v7(), 2, Routing::SmallestMailbox, Worker::factory(&release, &refuse)).await?;
+
+    let busy = router.members()[0].clone();
+    busy.send(Job::Block)?;
+    busy.send(Job::Who)?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let (idle, _) = router.members()[1].ask(Job::Handled).await??;
+    for _ in 0..3 {
+        assert_eq!(router.ask(Job::Who).await??.0, idle);
+    }
+
+    release.notify_one();
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn consistent_hash() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (release, refuse) = defaults();
+    let router = system.spawn_pool(Uuid::now_v7(), 4, Routing::ConsistentHash, Worker::factory(&release, &refuse)).await?;
+
+    for order in 0..8 {
+        let (first, _) = router.key(&order).ask(Job::Who).await??;
+        for _ in 0..3 {
+            assert_eq!(router.key(&order).ask(Job::Who).await??.0, first);
+        }
+    }
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn broadcast() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (release, refuse) = defaults();
+    let router = system.spawn_pool(Uuid::now_v7(), 3, Routing::Broadcast, Worker::factory(&release, &refuse)).await?;
+
+    router.send(Job::Who)?;
+    router.tell(Job::Who).await??;
+
+    for member in router.members() {
+        assert_eq!(member.ask(Job::Handled).await??.1, 2);
+    }
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn resize() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (release, refuse) = defaults();
+    let id = Uuid::now_v7();
+    let router = system.spawn_pool(id, 2, Routing::RoundRobin, Worker::factory(&release, &refuse)).await?;
+
+    router.resize(5).await?;
+    assert_eq!(router.size(), 5);
+
+    router.resize(1).await?;
+    assert_eq!(router.size(), 1);
+    assert!(matches!(system.find::<Worker>(format!("{}/4", id)).await, Err(ActorError::NotFoundActor { .. })));
+
+    router.shutdown().await?;
+    assert_eq!(router.size(), 0);
+    assert!(matches!(router.ask(Job::Who).await, Err(ActorError::NotFoundActor { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn replace_failed_members() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let (release, refuse) = defaults();
+    let router = system.spawn_pool(Uuid::now_v7(), 2, Routing::RoundRobin, Worker::factory(&release, &refuse)).await?;
+
+    let member = router.members()[0].clone();
+    let (failed, _) = member.ask(Job::Who).await??;
+
+    // restarted in place with a fresh instance.
+    assert!(member.ask(Job::Crash).await.is_err());
+    assert_eq!(member.ask(Job::Handled).await??.1, 0);
+
+    // the restarted instance fails to activate, so the member is stopped and replaced.
+    refuse.store(true, Ordering::SeqCst);
+    assert!(member.ask(Job::Crash).await.is_err());
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let members = router.members();
+    assert_eq!(members.len(), 2);
+    for member in members {
+        assert_ne!(member.ask(Job::Handled).await??.0, failed);
+    }
+
+    router.shutdown().await?;
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct Strict {
+    handled: u32,
+}
+
+impl Actor for Strict {
+    type Context = Context;
+
+    fn supervisor() -> SupervisorStrategy<Self> {
+        SupervisorStrategy::resume().decide_with(|e| match e {
+            ActorError::Rejected { .. } => Directive::Stop,
+            _ => Directive::Resume,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Handler<Job> for Strict {
+    type Accept = (String, u32);
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: Job, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            Job::Who => self.handled += 1,
+            Job::Handled | Job::Block => {}
+            Job::Crash => return Err(ActorError::NotEnoughValue),
+        }
+        Ok((ctx.id().to_string(), self.handled))
+    }
+}
+
+#[tokio::test]
+async fn members_follow_actor_supervisor() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let router = system.spawn_pool(Uuid::now_v7(), 2, Routing::RoundRobin, Strict::default).await?;
+
+    let member = router.members()[0].clone();
+    let (failed, _) = member.ask(Job::Who).await??;
+
+    // stopped by `Strict::supervisor` on rejection, then replaced by the pool.
+    assert!(member.ask(Job::Crash).await?.is_err());
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let members = router.members();
+    assert_eq!(members.len(), 2);
+    for member in members {
+        assert_ne!(member.ask(Job::Handled).await??.0, failed);
+    }
+
+    router.shutdown().await?;
+    Ok(())
+}
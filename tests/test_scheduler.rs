@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem};
+
+pub struct Ticks(u32);
+
+impl Actor for Ticks { type Context = Context; }
+
+#[derive(Clone)]
+pub enum Tick {
+    Up,
+    Count,
+}
+
+impl Message for Tick {}
+
+#[async_trait::async_trait]
+impl Handler<Tick> for Ticks {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: Tick, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        if let Tick::Up = msg {
+            self.0 += 1;
+        }
+        Ok(self.0)
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn schedule_once() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let refs = system.spawn(Uuid::now_v7(), Ticks(0)).await?;
+
+    let timer = system.schedule_once(&refs, Duration::from_secs(10), Tick::Up);
+
+    tokio::time::sleep(Duration::from_secs(9)).await;
+    assert_eq!(refs.ask(Tick::Count).await??, 0);
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert_eq!(refs.ask(Tick::Count).await??, 1);
+    assert!(timer.is_finished());
+
+    refs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn schedule_interval_until_cancelled() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let refs = system.spawn(Uuid::now_v7(), Ticks(0)).await?;
+
+    let timer = system.schedule_interval(&refs, Duration::from_secs(1), Tick::Up);
+
+    tokio::time::sleep(Duration::from_millis(3500)).await;
+    assert_eq!(refs.ask(Tick::Count).await??, 3);
+
+    timer.cancel();
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    assert_eq!(refs.ask(Tick::Count).await??, 3);
+    assert!(timer.is_finished());
+
+    refs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn schedule_interval_with_zero_period() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let refs = system.spawn(Uuid::now_v7(), Ticks(0)).await?;
+
+    let timer = system.schedule_interval(&refs, Duration::ZERO, Tick::Up);
+
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    assert!(!timer.is_finished());
+    assert!(refs.ask(Tick::Count).await?? > 0);
+
+    timer.cancel();
+    refs.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn timers_stop_with_target() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let refs = system.spawn(Uuid::now_v7(), Ticks(0)).await?;
+
+    let once = system.schedule_once(&refs, Duration::from_secs(60), Tick::Up);
+    let interval = system.schedule_interval(&refs, Duration::from_secs(1), Tick::Up);
+
+    refs.shutdown().await?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    assert!(once.is_finished());
+    assert!(interval.is_finished());
+    Ok(())
+}
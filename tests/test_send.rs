@@ -0,0 +1,85 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, DeadLetter, DeadLetterListener, DeadLetterReason, LutetiumActorSystem};
+
+#[derive(Clone, Default)]
+pub struct Letters(Arc<Mutex<Vec<DeadLetter>>>);
+
+impl DeadLetterListener for Letters {
+    fn receive(&self, letter: DeadLetter) {
+        self.0.lock().unwrap().push(letter);
+    }
+}
+
+pub struct Worker {
+    release: Arc<Notify>,
+    done: u32,
+}
+
+impl Actor for Worker { type Context = Context; }
+
+pub enum Work {
+    Slow,
+    Broken,
+    Count,
+}
+
+impl Message for Work {}
+
+#[async_trait::async_trait]
+impl Handler<Work> for Worker {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: Work, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            Work::Slow => {
+                self.release.notified().await;
+                self.done += 1;
+                Ok(self.done)
+            }
+            Work::Broken => Err(ActorError::NotEnoughValue),
+            Work::Count => Ok(self.done),
+        }
+    }
+}
+
+#[tokio::test]
+async fn send_does_not_wait_for_handler() -> anyhow::Result<()> {
+    let letters = Letters::default();
+    let mut system = ActorSystem::builder();
+    system.dead_letters(letters.clone());
+    let system = system.build();
+
+    let release = Arc::new(Notify::new());
+    let id = Uuid::now_v7();
+    let refs = system.spawn(id, Worker { release: Arc::clone(&release), done: 0 }).await?;
+
+    refs.send(Work::Slow)?;
+    refs.send(Work::Broken)?;
+    assert_eq!(letters.0.lock().unwrap().len(), 0);
+
+    release.notify_one();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(refs.ask(Work::Count).await??, 1);
+
+    {
+        let letters = letters.0.lock().unwrap();
+        assert_eq!(letters.len(), 1);
+        assert_eq!(letters[0].target().to_string(), id.to_string());
+        assert_eq!(letters[0].reason(), &DeadLetterReason::Rejected);
+        assert!(letters[0].message().ends_with("Work"));
+    }
+
+    refs.shutdown().await?;
+
+    Ok(())
+}
@@ -1,12 +1,14 @@
 #![allow(unused)]
 
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use tracing_subscriber::Layer;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use uuid::Uuid;
 
-use lutetium::actor::{Actor, ActorContext, Context, Handler, Message};
+use lutetium::actor::{Actor, ActorContext, Context, Handler, Message, StopReason};
 use lutetium::actor::refs::{DynRef, RegularAction};
 use lutetium::errors::ActorError;
 use lutetium::system::{ActorSystem, LutetiumActorSystem};
@@ -40,14 +42,14 @@ impl Handler<Command> for State {
     }
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn shutdown() -> anyhow::Result<()> {
-    tracing_subscriber::registry()
+    let _ = tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer()
             .with_filter(tracing_subscriber::EnvFilter::new("test=trace,lutetium=trace"))
             .with_filter(tracing_subscriber::filter::LevelFilter::TRACE),
         )
-        .init();
+        .try_init();
     
     let system = ActorSystem::builder().build();
     
@@ -58,18 +60,19 @@ async fn shutdown() -> anyhow::Result<()> {
     system.shutdown(&id).await?;
     
     tokio::time::sleep(Duration::from_millis(1000)).await;
+    assert!(!system.contains(id).await);
     
     Ok(())
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn shutdown_all() -> anyhow::Result<()> {
-    tracing_subscriber::registry()
+    let _ = tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer()
             .with_filter(tracing_subscriber::EnvFilter::new("test=trace,lutetium=trace"))
             .with_filter(tracing_subscriber::filter::LevelFilter::TRACE),
         )
-        .init();
+        .try_init();
     
     let system = ActorSystem::builder().build();
     
@@ -82,18 +85,19 @@ async fn shutdown_all() -> anyhow::Result<()> {
     system.shutdown_all().await?;
     
     tokio::time::sleep(Duration::from_millis(1000)).await;
+    assert_eq!(system.count().await, 0);
     
     Ok(())
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn self_shutdown() -> anyhow::Result<()> {
-    tracing_subscriber::registry()
+    let _ = tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer()
                   .with_filter(tracing_subscriber::EnvFilter::new("test=trace,lutetium=trace"))
                   .with_filter(tracing_subscriber::filter::LevelFilter::TRACE),
         )
-        .init();
+        .try_init();
 
     let system = ActorSystem::builder().build();
 
@@ -102,18 +106,20 @@ async fn self_shutdown() -> anyhow::Result<()> {
     refs.tell(Command).await??;
     
     tokio::time::sleep(Duration::from_millis(1000)).await;
+    assert!(!refs.is_active().await);
+    assert!(!system.contains(id).await);
     
     Ok(())
 }
 
-#[tokio::test]
+#[tokio::test(start_paused = true)]
 async fn refs_shutdown() -> anyhow::Result<()> {
-    tracing_subscriber::registry()
+    let _ = tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer()
                   .with_filter(tracing_subscriber::EnvFilter::new("test=trace,lutetium=trace"))
                   .with_filter(tracing_subscriber::filter::LevelFilter::TRACE),
         )
-        .init();
+        .try_init();
 
     let system = ActorSystem::builder().build();
 
@@ -123,6 +129,78 @@ async fn refs_shutdown() -> anyhow::Result<()> {
     refs.shutdown().await?;
     
     tokio::time::sleep(Duration::from_millis(1000)).await;
+    assert!(!refs.is_active().await);
+    assert!(!system.contains(id).await);
     
     Ok(())
-}
\ No newline at end of file
+}
+
+pub struct Slow;
+
+#[async_trait::async_trait]
+impl Actor for Slow {
+    type Context = Context;
+
+    async fn deactivate(&mut self, _: StopReason, _: &mut Context) -> Result<(), ActorError> {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Ok(())
+    }
+}
+
+pub struct Lingering {
+    finished: Arc<AtomicBool>,
+}
+
+#[async_trait::async_trait]
+impl Actor for Lingering {
+    type Context = Context;
+
+    async fn deactivate(&mut self, _: StopReason, _: &mut Context) -> Result<(), ActorError> {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        self.finished.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn shutdown_waits_lifecycle() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    
+    let id = Uuid::now_v7();
+    let finished = Arc::new(AtomicBool::new(false));
+    let refs = system.spawn(id, Lingering { finished: Arc::clone(&finished) }).await?;
+    
+    system.shutdown(&id).await?;
+    
+    assert!(finished.load(Ordering::SeqCst));
+    assert!(!refs.is_active().await);
+    assert!(matches!(system.find::<Lingering>(id).await, Err(ActorError::NotFoundActor { .. })));
+    
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn shutdown_all_timeout() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    
+    let fast = Uuid::now_v7();
+    system.spawn(fast, State { id: fast, state: 1 }).await?;
+    let slow = Uuid::now_v7();
+    system.spawn(slow, Slow).await?;
+    
+    let report = system.shutdown_all_timeout(Duration::from_millis(200)).await?;
+    
+    assert!(!report.is_complete());
+    assert_eq!(report.stopped().iter().map(ToString::to_string).collect::<Vec<_>>(), vec![fast.to_string()]);
+    assert_eq!(report.timed_out().iter().map(ToString::to_string).collect::<Vec<_>>(), vec![slow.to_string()]);
+    
+    let slow_again = Uuid::now_v7();
+    system.spawn(slow_again, Slow).await?;
+    
+    assert!(matches!(
+        system.shutdown_timeout(&slow_again, Duration::from_millis(200)).await, 
+        Err(ActorError::ShutdownTimeout { .. })
+    ));
+    
+    Ok(())
+}
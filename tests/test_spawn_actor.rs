@@ -97,12 +97,12 @@ impl Handler<PersonCommand> for Person {
 
 #[tokio::test]
 async fn main() -> anyhow::Result<()> {
-    tracing_subscriber::registry()
+    let _ = tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer()
             .with_filter(tracing_subscriber::EnvFilter::new("test=trace,lutetium=trace"))
             .with_filter(tracing_subscriber::filter::LevelFilter::TRACE),
         )
-        .init();
+        .try_init();
     
     
     let system = ActorSystem::builder().build();
@@ -131,12 +131,12 @@ async fn main() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn try_spawn() -> anyhow::Result<()> {
-    tracing_subscriber::registry()
+    let _ = tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer()
             .with_filter(tracing_subscriber::EnvFilter::new("test=trace,lutetium=trace"))
             .with_filter(tracing_subscriber::filter::LevelFilter::TRACE),
         )
-        .init();
+        .try_init();
     
     let system = ActorSystem::builder().build();
     
@@ -154,12 +154,12 @@ async fn try_spawn() -> anyhow::Result<()> {
 
 #[tokio::test]
 async fn spawn_from() -> anyhow::Result<()> {
-    tracing_subscriber::registry()
+    let _ = tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer()
                   .with_filter(tracing_subscriber::EnvFilter::new("test=trace,lutetium=trace"))
                   .with_filter(tracing_subscriber::filter::LevelFilter::TRACE),
         )
-        .init();
+        .try_init();
 
     let system = ActorSystem::builder().build();
     
@@ -172,4 +172,35 @@ async fn spawn_from() -> anyhow::Result<()> {
     refs.shutdown().await?;
     
     Ok(())
-}
\ No newline at end of file
+}
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_find_or() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let id = PersonId::default();
+    
+    let tasks = (0..8)
+        .map(|age| {
+            let system = system.clone();
+            tokio::spawn(async move {
+                system.find_or(id, move |id| async move {
+                    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                    Person { id, name: "RechellaTek".to_string(), age }
+                }).await
+            })
+        })
+        .collect::<Vec<_>>();
+    
+    let mut refs = Vec::new();
+    for task in tasks {
+        refs.push(task.await??);
+    }
+    
+    assert_eq!(system.count().await, 1);
+    for refs in &refs {
+        assert!(refs.is_active().await);
+    }
+    
+    system.shutdown_all().await?;
+    
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message, StopReason, SupervisorStrategy};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem, SpawnConfig};
+
+#[derive(Clone, Default)]
+pub struct Stopped(Arc<Mutex<Vec<StopReason>>>);
+
+impl Stopped {
+    fn reasons(&self) -> Vec<StopReason> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+pub struct Lease {
+    stopped: Stopped
+}
+
+#[async_trait::async_trait]
+impl Actor for Lease {
+    type Context = Context;
+
+    async fn deactivate(&mut self, reason: StopReason, _ctx: &mut Context) -> Result<(), ActorError> {
+        self.stopped.0.lock().unwrap().push(reason);
+        Ok(())
+    }
+}
+
+pub struct Explode;
+
+impl Message for Explode {}
+
+#[async_trait::async_trait]
+impl Handler<Explode> for Lease {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _: Explode, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        panic!("lease broken")
+    }
+}
+
+#[tokio::test]
+async fn stop_reasons() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let stopped = Stopped::default();
+
+    let refs = system.spawn(Uuid::now_v7(), Lease { stopped: stopped.clone() }).await?;
+    refs.shutdown().await?;
+
+    let refs = system.spawn(Uuid::now_v7(), Lease { stopped: stopped.clone() }).await?;
+    let _ = refs.ask(Explode).await;
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    system.spawn(Uuid::now_v7(), Lease { stopped: stopped.clone() }).await?;
+    system.shutdown_all().await?;
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(stopped.reasons(), vec![
+        StopReason::Terminated,
+        StopReason::Supervisor,
+        StopReason::SystemShutdown,
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deactivate_before_restart() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let stopped = Stopped::default();
+
+    let config = SpawnConfig::default()
+        .supervisor(SupervisorStrategy::restart({
+            let stopped = stopped.clone();
+            move || Lease { stopped: stopped.clone() }
+        }));
+    let refs = system.spawn_with(Uuid::now_v7(), Lease { stopped: stopped.clone() }, config).await?;
+    let _ = refs.ask(Explode).await;
+
+    assert!(refs.is_active().await);
+    refs.shutdown().await?;
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(stopped.reasons(), vec![
+        StopReason::Restarted,
+        StopReason::Terminated,
+    ]);
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Directive, Handler, Message, SupervisorStrategy};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem, SpawnConfig};
+
+#[derive(Debug, Default)]
+pub struct Counter {
+    count: u32
+}
+
+impl Actor for Counter { type Context = Context; }
+
+pub enum CounterCommand {
+    Increment,
+    Fail,
+    Corrupt,
+    Get,
+}
+
+impl Message for CounterCommand {}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CounterError {
+    #[error("counter failed.")]
+    Failed,
+    #[error("counter is corrupted.")]
+    Corrupted,
+}
+
+#[async_trait::async_trait]
+impl Handler<CounterCommand> for Counter {
+    type Accept = u32;
+    type Rejection = CounterError;
+
+    async fn call(&mut self, msg: CounterCommand, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            CounterCommand::Increment => {
+                self.count += 1;
+                Ok(self.count)
+            }
+            CounterCommand::Fail => {
+                self.count += 100;
+                Err(CounterError::Failed)
+            }
+            CounterCommand::Corrupt => {
+                self.count += 100;
+                Err(CounterError::Corrupted)
+            }
+            CounterCommand::Get => Ok(self.count),
+        }
+    }
+}
+
+#[tokio::test]
+async fn resume_keeps_state() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let refs = system.spawn(Uuid::now_v7(), Counter::default()).await?;
+
+    refs.ask(CounterCommand::Increment).await??;
+    assert!(refs.ask(CounterCommand::Fail).await?.is_err());
+
+    assert_eq!(refs.ask(CounterCommand::Get).await??, 101);
+
+    refs.shutdown().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn restart_with_fresh_actor() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let config = SpawnConfig::default()
+        .supervisor(SupervisorStrategy::restart(Counter::default));
+    let refs = system.spawn_with(Uuid::now_v7(), Counter::default(), config).await?;
+
+    refs.ask(CounterCommand::Increment).await??;
+    assert!(refs.ask(CounterCommand::Fail).await?.is_err());
+
+    assert_eq!(refs.ask(CounterCommand::Get).await??, 0);
+
+    refs.shutdown().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stop_removes_actor() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let id = Uuid::now_v7();
+    let config = SpawnConfig::default()
+        .supervisor(SupervisorStrategy::resume().decide_with(|e| match e {
+            ActorError::Rejected { .. } => Directive::Stop,
+            _ => Directive::Resume,
+        }));
+    let refs = system.spawn_with(id, Counter::default(), config).await?;
+
+    assert!(refs.ask(CounterCommand::Fail).await?.is_err());
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert!(!refs.is_active().await);
+    assert!(matches!(system.find::<Counter>(id).await, Err(ActorError::NotFoundActor { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn decide_by_rejection() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+
+    let config = SpawnConfig::default()
+        .supervisor(SupervisorStrategy::restart(Counter::default)
+            .decide_rejection::<CounterCommand, _>(|rejection| match rejection {
+                CounterError::Corrupted => Directive::Restart,
+                CounterError::Failed => Directive::Resume,
+            }));
+    let refs = system.spawn_with(Uuid::now_v7(), Counter::default(), config).await?;
+
+    assert!(refs.ask(CounterCommand::Fail).await?.is_err());
+    assert_eq!(refs.ask(CounterCommand::Get).await??, 100);
+
+    assert!(matches!(refs.ask(CounterCommand::Corrupt).await?, Err(CounterError::Corrupted)));
+    assert_eq!(refs.ask(CounterCommand::Get).await??, 0);
+
+    refs.shutdown().await?;
+
+    Ok(())
+}
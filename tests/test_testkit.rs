@@ -0,0 +1,175 @@
+#![cfg(feature = "testkit")]
+
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, ActorContext, Context, Handler, Message, StopReason};
+use lutetium::actor::refs::{Recipient, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::LutetiumActorSystem;
+use lutetium::testkit::{deterministic_runtime, TestKit, TestProbe};
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct OrderPlaced {
+    id: u32,
+}
+
+impl Message for OrderPlaced {}
+
+pub struct Orders {
+    placed: Recipient<OrderPlaced>,
+    count: u32,
+}
+
+impl Actor for Orders { type Context = Context; }
+
+pub enum OrderCommand {
+    Place(u32),
+    Delay(Duration),
+    Close,
+}
+
+impl Message for OrderCommand {}
+
+#[async_trait::async_trait]
+impl Handler<OrderCommand> for Orders {
+    type Accept = u32;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: OrderCommand, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            OrderCommand::Place(id) => {
+                self.count += 1;
+                self.placed.send(OrderPlaced { id })?;
+            }
+            OrderCommand::Delay(delay) => tokio::time::sleep(delay).await,
+            OrderCommand::Close => ctx.shutdown().await,
+        }
+        Ok(self.count)
+    }
+}
+
+#[tokio::test]
+async fn probe_records_messages() {
+    let kit = TestKit::new();
+    let mut probe = kit.probe::<OrderPlaced>();
+    let orders = kit.spawn(Orders { placed: probe.recipient(), count: 0 }).await;
+
+    assert_eq!(kit.ask(&orders, OrderCommand::Place(1)).await.unwrap(), 1);
+    assert_eq!(probe.expect_msg().await, OrderPlaced { id: 1 });
+    probe.expect_no_msg(Duration::from_millis(20)).await;
+
+    kit.ask(&orders, OrderCommand::Place(2)).await.unwrap();
+    kit.ask(&orders, OrderCommand::Place(3)).await.unwrap();
+    assert_eq!(probe.receive_n(2).await, vec![OrderPlaced { id: 2 }, OrderPlaced { id: 3 }]);
+
+    kit.shutdown().await;
+}
+
+#[tokio::test]
+async fn expect_stop_reason() {
+    let kit = TestKit::new();
+    let probe = TestProbe::<OrderPlaced>::new();
+    let orders = kit.spawn(Orders { placed: probe.recipient(), count: 0 }).await;
+
+    kit.ask(&orders, OrderCommand::Close).await.unwrap();
+    assert_eq!(kit.expect_stop(&orders).await, StopReason::Terminated);
+}
+
+#[tokio::test]
+#[should_panic(expected = "received no message")]
+async fn expect_msg_times_out() {
+    let mut probe = TestProbe::<OrderPlaced>::new()
+        .timeout(Duration::from_millis(10));
+    probe.expect_msg().await;
+}
+
+pub struct Stubborn;
+
+#[async_trait::async_trait]
+impl Actor for Stubborn {
+    type Context = Context;
+
+    async fn deactivate(&mut self, _reason: StopReason, _ctx: &mut Context) -> Result<(), ActorError> {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        Ok(())
+    }
+}
+
+#[tokio::test(start_paused = true)]
+#[should_panic(expected = "did not stop within")]
+async fn shutdown_reports_running_actors() {
+    let kit = TestKit::new().timeout(Duration::from_secs(1));
+    kit.spawn(Stubborn).await;
+    kit.shutdown().await;
+}
+
+#[tokio::test(start_paused = true)]
+async fn run_until_idle_drains_mailboxes() {
+    let kit = TestKit::new();
+    let mut probe = kit.probe::<OrderPlaced>();
+    let orders = kit.spawn(Orders { placed: probe.recipient(), count: 0 }).await;
+
+    let started = tokio::time::Instant::now();
+    orders.send(OrderCommand::Place(1)).unwrap();
+    orders.send(OrderCommand::Delay(Duration::from_secs(60))).unwrap();
+    orders.send(OrderCommand::Place(2)).unwrap();
+
+    kit.run_until_idle().await;
+
+    assert!(started.elapsed() >= Duration::from_secs(60));
+    assert_eq!(probe.expect_msg_within(Duration::ZERO).await, OrderPlaced { id: 1 });
+    assert_eq!(probe.expect_msg_within(Duration::ZERO).await, OrderPlaced { id: 2 });
+
+    orders.send(OrderCommand::Close).unwrap();
+    kit.run_until_idle().await;
+
+    assert_eq!(kit.system().count().await, 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn run_until_idle_waits_for_stopping_actors() {
+    let kit = TestKit::new();
+    let id = Uuid::now_v7();
+    let stubborn = kit.system().spawn(id, Stubborn).await.unwrap();
+
+    assert!(matches!(
+        kit.system().shutdown_timeout(&id, Duration::from_millis(200)).await,
+        Err(ActorError::ShutdownTimeout { .. })
+    ));
+    assert!(kit.system().contains(id).await);
+
+    kit.run_until_idle().await;
+
+    assert_eq!(kit.expect_stop(&stubborn).await, StopReason::Terminated);
+    assert_eq!(kit.system().count().await, 0);
+}
+
+fn place_concurrently() -> Vec<u32> {
+    deterministic_runtime().block_on(async {
+        let kit = TestKit::new();
+        let mut probe = kit.probe::<OrderPlaced>();
+        let first = kit.spawn(Orders { placed: probe.recipient(), count: 0 }).await;
+        let second = kit.spawn(Orders { placed: probe.recipient(), count: 0 }).await;
+
+        for id in 0..3 {
+            first.send(OrderCommand::Place(id)).unwrap();
+            second.send(OrderCommand::Place(id + 10)).unwrap();
+        }
+        kit.run_until_idle().await;
+
+        probe.receive_n(6).await
+            .into_iter()
+            .map(|placed| placed.id)
+            .collect()
+    })
+}
+
+#[test]
+fn deterministic_order() {
+    let placed = place_concurrently();
+    for _ in 0..10 {
+        assert_eq!(place_concurrently(), placed);
+    }
+}
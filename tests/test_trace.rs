@@ -0,0 +1,97 @@
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Instrument, Level, Subscriber};
+use tracing_subscriber::layer::{Context as LayerContext, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, Context, Handler, Message};
+use lutetium::actor::refs::{DynRef, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::system::{ActorSystem, LutetiumActorSystem};
+
+#[derive(Debug, Clone)]
+struct Recorded {
+    name: &'static str,
+    level: Level,
+    parent: Option<&'static str>,
+    fields: Vec<String>,
+}
+
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<Recorded>>>);
+
+struct Fields(Vec<String>);
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push(format!("{}={}", field.name(), value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl<S> Layer<S> for Recorder
+    where S: Subscriber + for<'a> LookupSpan<'a>
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: LayerContext<'_, S>) {
+        let mut fields = Fields(Vec::new());
+        attrs.record(&mut fields);
+        let parent = ctx.span(id)
+            .and_then(|span| span.parent())
+            .map(|parent| parent.name());
+        self.0.lock().unwrap().push(Recorded { name: attrs.metadata().name(), level: *attrs.metadata().level(), parent, fields: fields.0 });
+    }
+}
+
+pub struct Greeter;
+
+impl Actor for Greeter { type Context = Context; }
+
+pub struct Greet;
+
+impl Message for Greet {}
+
+#[async_trait::async_trait]
+impl Handler<Greet> for Greeter {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, _msg: Greet, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn handler_span_is_child_of_caller() -> anyhow::Result<()> {
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let system = ActorSystem::builder().build();
+    let id = Uuid::now_v7();
+    let refs = system.spawn(id, Greeter).await?;
+
+    async {
+        refs.ask(Greet).await
+    }.instrument(tracing::info_span!("caller")).await??;
+
+    let handled = recorder.0.lock().unwrap()
+        .iter()
+        .find(|span| span.name == "handle")
+        .cloned()
+        .expect("handler span was not recorded");
+
+    assert_eq!(handled.parent, Some("caller"));
+    assert_eq!(handled.level, Level::DEBUG);
+    assert!(handled.fields.iter().any(|field| field.starts_with("message=") && field.ends_with("Greet")));
+    assert!(handled.fields.contains(&format!("actor_id={}", id)));
+    assert!(handled.fields.iter().any(|field| field.starts_with("queue_wait=")));
+
+    refs.shutdown().await?;
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use lutetium::actor::{Actor, ActorContext, Context, Handler, Message, StopReason, Terminated};
+use lutetium::actor::refs::{ActorRef, DynRef, MailboxConfig, OverflowPolicy, RegularAction};
+use lutetium::errors::ActorError;
+use lutetium::identifier::ToActorId;
+use lutetium::system::{ActorSystem, LutetiumActorSystem, SpawnConfig};
+
+pub struct Target;
+
+impl Actor for Target { type Context = Context; }
+
+#[derive(Default)]
+pub struct Watcher {
+    seen: Vec<Terminated>,
+}
+
+impl Actor for Watcher { type Context = Context; }
+
+pub enum WatchCommand {
+    Watch(ActorRef<Target>),
+    Unwatch(ActorRef<Target>),
+    Stall(Duration),
+    Seen,
+}
+
+impl Message for WatchCommand {}
+
+#[async_trait::async_trait]
+impl Handler<WatchCommand> for Watcher {
+    type Accept = Vec<Terminated>;
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: WatchCommand, ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        match msg {
+            WatchCommand::Watch(target) => ctx.watch::<Self, _>(&target)?,
+            WatchCommand::Unwatch(target) => ctx.unwatch(&target),
+            WatchCommand::Stall(duration) => tokio::time::sleep(duration).await,
+            WatchCommand::Seen => {}
+        }
+        Ok(self.seen.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl Handler<Terminated> for Watcher {
+    type Accept = ();
+    type Rejection = ActorError;
+
+    async fn call(&mut self, msg: Terminated, _ctx: &mut Context) -> Result<Self::Accept, Self::Rejection> {
+        self.seen.push(msg);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn notified_when_watched_actor_stops() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let watcher = system.spawn(Uuid::now_v7(), Watcher::default()).await?;
+
+    let id = Uuid::now_v7();
+    let target = system.spawn(id, Target).await?;
+    watcher.ask(WatchCommand::Watch(target.clone())).await??;
+
+    system.shutdown(&id).await?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let seen = watcher.ask(WatchCommand::Seen).await??;
+    assert_eq!(seen, vec![Terminated { id: id.to_actor_id(), reason: StopReason::Terminated }]);
+
+    watcher.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn unwatch_stops_notifications() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let watcher = system.spawn(Uuid::now_v7(), Watcher::default()).await?;
+
+    let id = Uuid::now_v7();
+    let target = system.spawn(id, Target).await?;
+    watcher.ask(WatchCommand::Watch(target.clone())).await??;
+    watcher.ask(WatchCommand::Unwatch(target.clone())).await??;
+
+    system.shutdown(&id).await?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    assert!(watcher.ask(WatchCommand::Seen).await??.is_empty());
+
+    watcher.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_stopped_actor() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let watcher = system.spawn(Uuid::now_v7(), Watcher::default()).await?;
+
+    let id = Uuid::now_v7();
+    let target = system.spawn(id, Target).await?;
+    system.shutdown(&id).await?;
+
+    watcher.ask(WatchCommand::Watch(target)).await??;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    assert_eq!(watcher.ask(WatchCommand::Seen).await??.len(), 1);
+
+    watcher.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn notified_through_full_mailbox() -> anyhow::Result<()> {
+    let system = ActorSystem::builder().build();
+    let config = SpawnConfig::default()
+        .mailbox(MailboxConfig::bounded(1, OverflowPolicy::Wait));
+    let watcher = system.spawn_with(Uuid::now_v7(), Watcher::default(), config).await?;
+
+    let id = Uuid::now_v7();
+    let target = system.spawn(id, Target).await?;
+    watcher.ask(WatchCommand::Watch(target.clone())).await??;
+
+    watcher.send(WatchCommand::Stall(Duration::from_millis(50)))?;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    watcher.send(WatchCommand::Seen)?;
+
+    system.shutdown(&id).await?;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(watcher.ask(WatchCommand::Seen).await??.len(), 1);
+
+    watcher.shutdown().await?;
+    Ok(())
+}